@@ -19,26 +19,31 @@ impl CachedPortfolio {
         self.inner.get(instrument_id).map(Arc::clone)
     }
 
+    /// Позиции без идентификатора инструмента не кешируются.
     #[inline]
     pub fn insert(&mut self, portfolio_position: types::PortfolioPosition) {
-        let key = portfolio_position.instrument_uid.clone();
+        let Some(key) = portfolio_position.instrument_uid.clone() else {
+            return;
+        };
         let value = Arc::new(RwLock::new(portfolio_position));
         self.inner.insert(key, value);
     }
 
     #[inline]
     pub fn update(&mut self, portfolio_position: types::PortfolioPosition) {
-        let key = portfolio_position.instrument_uid.clone();
+        let Some(key) = portfolio_position.instrument_uid.clone() else {
+            return;
+        };
         if let Some(lock) = self.inner.get(&key) {
-            let mut value = lock.write().unwrap();
-            value.quantity_total = portfolio_position.quantity_total;
-            value.quantity_blocked = portfolio_position.quantity_blocked;
+            *lock.write().unwrap() = portfolio_position;
         }
     }
 
     #[inline]
     pub fn upsert(&mut self, portfolio_position: types::PortfolioPosition) {
-        let key = portfolio_position.instrument_uid.clone();
+        let Some(key) = portfolio_position.instrument_uid.clone() else {
+            return;
+        };
         if self.inner.contains_key(&key) {
             self.update(portfolio_position);
         } else {
@@ -1,16 +1,37 @@
-use std::error::Error;
+use std::collections::HashMap;
 use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinSet;
+use tokio_stream::wrappers::ReceiverStream;
 use uuid::Uuid;
 
-use crate::{TinkoffInvestError, TinkoffInvestInterceptor, enums, traits, types};
+use crate::rate_limiter::{RateLimitedChannel, RateLimiter};
+use crate::retry::RetryPolicy;
+use crate::{Error, TinkoffInvestError, TinkoffInvestInterceptor, enums, traits, types};
 use tinkoff_invest_types::{
-    self, CancelOrderRequest, GetAccountsRequest, GetCandlesRequest, GetOrderBookRequest,
-    GetTradingStatusRequest, InstrumentIdType, InstrumentRequest, InstrumentsRequest,
-    OperationsRequest, OrderIdType, PortfolioRequest, PositionsRequest, PostOrderRequest,
-    instruments_service_client::InstrumentsServiceClient,
+    self, AssetRequest, AssetsRequest, BrokerReportRequest, CancelOrderRequest,
+    CancelStopOrderRequest, CloseSandboxAccountRequest, CreateFavoriteGroupRequest,
+    DeleteFavoriteGroupRequest, EditFavoritesActionType, EditFavoritesRequest,
+    EditFavoritesRequestInstrument, FavoriteInstrument, FilterOptionsRequest,
+    FindInstrumentRequest, GenerateBrokerReportRequest,
+    GenerateDividendsForeignIssuerReportRequest, GetAccountsRequest, GetAccruedInterestsRequest,
+    GetAssetFundamentalsRequest, GetBondCouponsRequest, GetBondEventsRequest,
+    GetBrokerReportRequest, GetCandlesRequest, GetClosePricesRequest,
+    GetDividendsForeignIssuerReportRequest, GetDividendsForeignIssuerRequest, GetDividendsRequest,
+    GetFavoriteGroupsRequest, GetFavoritesRequest, GetForecastRequest, GetFuturesMarginRequest,
+    GetInfoRequest, GetLastPricesRequest, GetLastTradesRequest, GetMarginAttributesRequest,
+    GetMaxLotsRequest, GetOperationsByCursorRequest, GetOperationsByCursorResponse,
+    GetOrderBookRequest, GetOrderPriceRequest, GetOrdersRequest, GetStopOrdersRequest,
+    GetTradingStatusRequest, GetUserTariffRequest, InstrumentClosePriceRequest, InstrumentIdType,
+    InstrumentRequest, InstrumentsRequest, OpenSandboxAccountRequest, OperationsRequest,
+    PortfolioRequest, PositionsRequest, PostOrderRequest, PostStopOrderRequest,
+    ReplaceOrderRequest, SandboxPayInRequest, TradingSchedulesRequest, WithdrawLimitsRequest,
+    broker_report_request, broker_report_response, get_dividends_foreign_issuer_request,
+    get_dividends_foreign_issuer_response, instruments_service_client::InstrumentsServiceClient,
     market_data_service_client::MarketDataServiceClient,
     operations_service_client::OperationsServiceClient, orders_service_client::OrdersServiceClient,
-    portfolio_request::CurrencyRequest, users_service_client::UsersServiceClient,
+    portfolio_request::CurrencyRequest, sandbox_service_client::SandboxServiceClient,
+    stop_orders_service_client::StopOrdersServiceClient, users_service_client::UsersServiceClient,
 };
 use tonic::{
     codec::CompressionEncoding,
@@ -19,17 +40,71 @@ use tonic::{
     transport::{Channel, ClientTlsConfig, Endpoint},
 };
 
+const PRODUCTION_URL: &str = "https://invest-public-api.tinkoff.ru";
+const SANDBOX_URL: &str = "https://sandbox-invest-public-api.tinkoff.ru";
+
+/// Код ошибки брокера: отчёт ещё формируется.
+const REPORT_NOT_READY_CODE: u32 = 30058;
+const REPORT_POLL_ATTEMPTS: u32 = 20;
+const REPORT_POLL_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const REPORT_POLL_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+#[inline]
+fn default_endpoint(url: &'static str) -> Endpoint {
+    Channel::from_static(url)
+        .tls_config(ClientTlsConfig::new().with_native_roots())
+        .unwrap()
+        .timeout(Duration::from_millis(10000))
+}
+
+/// Запрашивает страницу отчёта, пока отчёт формируется.
+/// `f` возвращает `None`, если страница ещё не готова.
+async fn poll_report<T, F, Fut>(f: F) -> Result<T, Error>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<Option<T>, Error>>,
+{
+    let mut backoff = REPORT_POLL_INITIAL_BACKOFF;
+    for attempt in 1..=REPORT_POLL_ATTEMPTS {
+        match f().await {
+            Ok(Some(value)) => return Ok(value),
+            Ok(None) => {}
+            Err(error) if error.code() == Some(REPORT_NOT_READY_CODE) => {}
+            Err(error) => return Err(error),
+        }
+        if attempt < REPORT_POLL_ATTEMPTS {
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(REPORT_POLL_MAX_BACKOFF);
+        }
+    }
+    Err(TinkoffInvestError::ReportNotReady.into())
+}
+
+#[inline]
+fn favorite_uids(instruments: Vec<FavoriteInstrument>) -> Vec<types::Uid> {
+    instruments
+        .into_iter()
+        .filter_map(|x| x.uid.parse().ok())
+        .collect()
+}
+
 pub struct TinkoffInvestBuilder<I>
 where
     I: Interceptor + Clone,
 {
-    endpoint: Endpoint,
+    endpoint: Option<Endpoint>,
     interceptor: Option<I>,
+    sandbox_mode: bool,
     enable_users_service_client: bool,
     enable_instruments_service_client: bool,
     enable_market_data_service_client: bool,
     enable_operations_service_client: bool,
     enable_orders_service_client: bool,
+    enable_stop_orders_service_client: bool,
+    rate_limits: HashMap<enums::Service, u32>,
+    rate_limits_from_tariff: bool,
+    query_user_info: bool,
+    retry_policy: RetryPolicy,
 }
 
 impl<I> TinkoffInvestBuilder<I>
@@ -38,24 +113,26 @@ where
 {
     #[inline]
     pub fn new() -> Self {
-        let endpoint = Channel::from_static("https://invest-public-api.tinkoff.ru")
-            .tls_config(ClientTlsConfig::new().with_native_roots())
-            .unwrap()
-            .timeout(Duration::from_millis(10000));
         Self {
-            endpoint,
+            endpoint: None,
             interceptor: None,
+            sandbox_mode: false,
             enable_users_service_client: false,
             enable_instruments_service_client: false,
             enable_market_data_service_client: false,
             enable_operations_service_client: false,
             enable_orders_service_client: false,
+            enable_stop_orders_service_client: false,
+            rate_limits: HashMap::new(),
+            rate_limits_from_tariff: false,
+            query_user_info: false,
+            retry_policy: RetryPolicy::disabled(),
         }
     }
 
     #[inline]
     pub fn endpoint(&mut self, endpoint: Endpoint) -> &Self {
-        self.endpoint = endpoint;
+        self.endpoint = Some(endpoint);
         self
     }
 
@@ -65,6 +142,15 @@ where
         self
     }
 
+    /// Переключает клиент на песочницу: направляет запросы по заявкам, портфелю, позициям,
+    /// операциям и счетам в `SandboxService` и, если адрес не задан через `endpoint`,
+    /// подключается к адресу песочницы.
+    #[inline]
+    pub fn sandbox_mode(&mut self, value: bool) -> &Self {
+        self.sandbox_mode = value;
+        self
+    }
+
     #[inline]
     pub fn enable_users_service_client(&mut self, value: bool) -> &Self {
         self.enable_users_service_client = value;
@@ -96,14 +182,62 @@ where
     }
 
     #[inline]
-    pub async fn build(self) -> Result<TinkoffInvest<I>, Box<dyn Error>> {
-        let channel = self.endpoint.clone().connect().await?;
+    pub fn enable_stop_orders_service_client(&mut self, value: bool) -> &Self {
+        self.enable_stop_orders_service_client = value;
+        self
+    }
+
+    /// Задаёт лимит unary-запросов в минуту для сервиса.
+    /// Имеет приоритет над лимитами из тарифа.
+    #[inline]
+    pub fn rate_limit(&mut self, service: enums::Service, limit_per_minute: u32) -> &Self {
+        self.rate_limits.insert(service, limit_per_minute);
+        self
+    }
+
+    /// Запрашивает лимиты unary-запросов из тарифа пользователя (`GetUserTariff`) при сборке клиента.
+    /// Требует включённого клиента `UsersService`.
+    #[inline]
+    pub fn rate_limits_from_tariff(&mut self, value: bool) -> &Self {
+        self.rate_limits_from_tariff = value;
+        self
+    }
+
+    /// Запрашивает информацию о пользователе (`GetInfo`) и его тариф (`GetUserTariff`) при сборке клиента.
+    /// Требует включённого клиента `UsersService`.
+    #[inline]
+    pub fn query_user_info(&mut self, value: bool) -> &Self {
+        self.query_user_info = value;
+        self
+    }
+
+    /// Задаёт политику повтора запросов при временных ошибках. По умолчанию запросы не повторяются.
+    #[inline]
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) -> &Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    #[inline]
+    pub async fn build(self) -> Result<TinkoffInvest<I>, Error> {
+        let endpoint = self.endpoint.unwrap_or_else(|| {
+            default_endpoint(if self.sandbox_mode {
+                SANDBOX_URL
+            } else {
+                PRODUCTION_URL
+            })
+        });
+        let channel = endpoint.clone().connect().await?;
         let interceptor = self
             .interceptor
             .ok_or(TinkoffInvestError::InterceptorNotSet)?;
+        let rate_limiter = RateLimiter::new(&self.rate_limits);
+        let rate_limited_channel = RateLimitedChannel::new(channel.clone(), rate_limiter.clone());
         let users_service_client = if self.enable_users_service_client {
-            let mut client =
-                UsersServiceClient::with_interceptor(channel.clone(), interceptor.clone());
+            let mut client = UsersServiceClient::with_interceptor(
+                rate_limited_channel.clone(),
+                interceptor.clone(),
+            );
             client = client.send_compressed(CompressionEncoding::Gzip);
             client = client.accept_compressed(CompressionEncoding::Gzip);
             client = client.max_decoding_message_size(256 * 1024 * 1024);
@@ -112,8 +246,10 @@ where
             None
         };
         let instruments_service_client = if self.enable_instruments_service_client {
-            let mut client =
-                InstrumentsServiceClient::with_interceptor(channel.clone(), interceptor.clone());
+            let mut client = InstrumentsServiceClient::with_interceptor(
+                rate_limited_channel.clone(),
+                interceptor.clone(),
+            );
             client = client.send_compressed(CompressionEncoding::Gzip);
             client = client.accept_compressed(CompressionEncoding::Gzip);
             client = client.max_decoding_message_size(256 * 1024 * 1024);
@@ -122,8 +258,10 @@ where
             None
         };
         let market_data_service_client = if self.enable_market_data_service_client {
-            let mut client =
-                MarketDataServiceClient::with_interceptor(channel.clone(), interceptor.clone());
+            let mut client = MarketDataServiceClient::with_interceptor(
+                rate_limited_channel.clone(),
+                interceptor.clone(),
+            );
             client = client.send_compressed(CompressionEncoding::Gzip);
             client = client.accept_compressed(CompressionEncoding::Gzip);
             client = client.max_decoding_message_size(256 * 1024 * 1024);
@@ -132,8 +270,10 @@ where
             None
         };
         let operations_service_client = if self.enable_operations_service_client {
-            let mut client =
-                OperationsServiceClient::with_interceptor(channel.clone(), interceptor.clone());
+            let mut client = OperationsServiceClient::with_interceptor(
+                rate_limited_channel.clone(),
+                interceptor.clone(),
+            );
             client = client.send_compressed(CompressionEncoding::Gzip);
             client = client.accept_compressed(CompressionEncoding::Gzip);
             client = client.max_decoding_message_size(256 * 1024 * 1024);
@@ -142,8 +282,34 @@ where
             None
         };
         let orders_service_client = if self.enable_orders_service_client {
-            let mut client =
-                OrdersServiceClient::with_interceptor(channel.clone(), interceptor.clone());
+            let mut client = OrdersServiceClient::with_interceptor(
+                rate_limited_channel.clone(),
+                interceptor.clone(),
+            );
+            client = client.send_compressed(CompressionEncoding::Gzip);
+            client = client.accept_compressed(CompressionEncoding::Gzip);
+            client = client.max_decoding_message_size(256 * 1024 * 1024);
+            Some(client)
+        } else {
+            None
+        };
+        let stop_orders_service_client = if self.enable_stop_orders_service_client {
+            let mut client = StopOrdersServiceClient::with_interceptor(
+                rate_limited_channel.clone(),
+                interceptor.clone(),
+            );
+            client = client.send_compressed(CompressionEncoding::Gzip);
+            client = client.accept_compressed(CompressionEncoding::Gzip);
+            client = client.max_decoding_message_size(256 * 1024 * 1024);
+            Some(client)
+        } else {
+            None
+        };
+        let sandbox_service_client = if self.sandbox_mode {
+            let mut client = SandboxServiceClient::with_interceptor(
+                rate_limited_channel.clone(),
+                interceptor.clone(),
+            );
             client = client.send_compressed(CompressionEncoding::Gzip);
             client = client.accept_compressed(CompressionEncoding::Gzip);
             client = client.max_decoding_message_size(256 * 1024 * 1024);
@@ -151,16 +317,41 @@ where
         } else {
             None
         };
+        let mut user_info = None;
+        let mut user_tariff = None;
+        if self.rate_limits_from_tariff || self.query_user_info {
+            let client = users_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::UsersServiceClientNotInit)?;
+            let tariff = client
+                .clone()
+                .get_user_tariff(GetUserTariffRequest {})
+                .await?
+                .into_inner();
+            if self.rate_limits_from_tariff {
+                rate_limiter.set_tariff(&tariff);
+            }
+            if self.query_user_info {
+                let info = client.clone().get_info(GetInfoRequest {}).await?;
+                user_info = Some(info.into_inner().into());
+                user_tariff = Some(tariff.into());
+            }
+        }
         Ok(TinkoffInvest {
             account: None,
-            endpoint: self.endpoint,
+            endpoint,
             channel,
             interceptor,
+            retry_policy: self.retry_policy,
+            user_info,
+            user_tariff,
             users_service_client,
             instruments_service_client,
             market_data_service_client,
             operations_service_client,
             orders_service_client,
+            stop_orders_service_client,
+            sandbox_service_client,
         })
     }
 }
@@ -174,6 +365,7 @@ where
     }
 }
 
+#[derive(Clone)]
 pub struct TinkoffInvest<I>
 where
     I: Interceptor,
@@ -182,30 +374,54 @@ where
     pub(crate) endpoint: Endpoint,
     pub(crate) channel: Channel,
     pub(crate) interceptor: I,
-    users_service_client: Option<UsersServiceClient<InterceptedService<Channel, I>>>,
-    instruments_service_client: Option<InstrumentsServiceClient<InterceptedService<Channel, I>>>,
-    market_data_service_client: Option<MarketDataServiceClient<InterceptedService<Channel, I>>>,
-    operations_service_client: Option<OperationsServiceClient<InterceptedService<Channel, I>>>,
-    orders_service_client: Option<OrdersServiceClient<InterceptedService<Channel, I>>>,
+    retry_policy: RetryPolicy,
+    user_info: Option<types::UserInfo>,
+    user_tariff: Option<types::UserTariff>,
+    users_service_client: Option<UsersServiceClient<InterceptedService<RateLimitedChannel, I>>>,
+    instruments_service_client:
+        Option<InstrumentsServiceClient<InterceptedService<RateLimitedChannel, I>>>,
+    market_data_service_client:
+        Option<MarketDataServiceClient<InterceptedService<RateLimitedChannel, I>>>,
+    operations_service_client:
+        Option<OperationsServiceClient<InterceptedService<RateLimitedChannel, I>>>,
+    orders_service_client: Option<OrdersServiceClient<InterceptedService<RateLimitedChannel, I>>>,
+    stop_orders_service_client:
+        Option<StopOrdersServiceClient<InterceptedService<RateLimitedChannel, I>>>,
+    sandbox_service_client: Option<SandboxServiceClient<InterceptedService<RateLimitedChannel, I>>>,
 }
 
 impl TinkoffInvest<TinkoffInvestInterceptor> {
-    pub async fn new(token: String) -> Result<Self, Box<dyn Error>> {
+    pub async fn new(token: String) -> Result<Self, Error> {
+        let interceptor = TinkoffInvestInterceptor::new(token);
+        let mut builder = TinkoffInvestBuilder::new();
+        builder.interceptor(Some(interceptor));
+        builder.enable_users_service_client(true);
+        builder.enable_instruments_service_client(true);
+        builder.enable_market_data_service_client(true);
+        builder.enable_operations_service_client(true);
+        builder.enable_orders_service_client(true);
+        builder.enable_stop_orders_service_client(true);
+        builder.build().await
+    }
+
+    pub async fn new_sandbox(token: String) -> Result<Self, Error> {
         let interceptor = TinkoffInvestInterceptor::new(token);
         let mut builder = TinkoffInvestBuilder::new();
         builder.interceptor(Some(interceptor));
+        builder.sandbox_mode(true);
         builder.enable_users_service_client(true);
         builder.enable_instruments_service_client(true);
         builder.enable_market_data_service_client(true);
         builder.enable_operations_service_client(true);
         builder.enable_orders_service_client(true);
+        builder.enable_stop_orders_service_client(true);
         builder.build().await
     }
 }
 
 impl<I> TinkoffInvest<I>
 where
-    I: Interceptor,
+    I: Interceptor + Clone,
 {
     #[inline]
     pub fn set_account(&mut self, account: Option<types::Account>) -> &Self {
@@ -213,34 +429,97 @@ where
         self
     }
 
-    pub async fn accounts(&mut self) -> Result<Vec<types::Account>, Box<dyn Error>> {
-        let client = self
-            .users_service_client
-            .as_mut()
-            .ok_or(TinkoffInvestError::UsersServiceClientNotInit)?;
+    /// Информация о пользователе, полученная при сборке клиента.
+    #[inline]
+    pub fn initial_user_info(&self) -> Option<&types::UserInfo> {
+        self.user_info.as_ref()
+    }
+
+    /// Тариф пользователя, полученный при сборке клиента.
+    #[inline]
+    pub fn initial_user_tariff(&self) -> Option<&types::UserTariff> {
+        self.user_tariff.as_ref()
+    }
+
+    pub async fn accounts(&self) -> Result<Vec<types::Account>, Error> {
         let request = GetAccountsRequest {
             ..Default::default()
         };
-        let accounts = client.get_accounts(request).await?.into_inner().accounts;
+        let accounts = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_sandbox_accounts(request).await
+                })
+                .await?
+                .into_inner()
+                .accounts
+        } else {
+            let client = self
+                .users_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::UsersServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_accounts(request).await
+                })
+                .await?
+                .into_inner()
+                .accounts
+        };
         Ok(accounts.iter().map(|v| v.clone().into()).collect())
     }
 
+    pub async fn user_info(&self) -> Result<types::UserInfo, Error> {
+        let client = self
+            .users_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::UsersServiceClientNotInit)?;
+        let response = self
+            .retry_policy
+            .call(
+                client,
+                GetInfoRequest {},
+                |mut client, request| async move { client.get_info(request).await },
+            )
+            .await?;
+        Ok(response.into_inner().into())
+    }
+
+    pub async fn user_tariff(&self) -> Result<types::UserTariff, Error> {
+        let client = self
+            .users_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::UsersServiceClientNotInit)?;
+        let response = self
+            .retry_policy
+            .call(
+                client,
+                GetUserTariffRequest {},
+                |mut client, request| async move { client.get_user_tariff(request).await },
+            )
+            .await?;
+        Ok(response.into_inner().into())
+    }
+
     pub async fn market_instruments(
-        &mut self,
+        &self,
         instrument_type: enums::InstrumentType,
-    ) -> Result<Vec<types::MarketInstrument>, Box<dyn Error>> {
+    ) -> Result<Vec<types::MarketInstrument>, Error> {
         match instrument_type {
             enums::InstrumentType::Share => self.shares().await,
             enums::InstrumentType::Currency => self.currencies().await,
             enums::InstrumentType::Future => self.futures().await,
-            // enums::InstrumentType::Option => self.options().await,
+            enums::InstrumentType::Bond => self.bonds().await,
+            enums::InstrumentType::Etf => self.etfs().await,
+            enums::InstrumentType::Option => self.options().await,
         }
     }
 
+    /// Инструмент по FIGI. Опционы также ищутся по FIGI.
     pub async fn market_instrument<T>(
-        &mut self,
+        &self,
         instrument: T,
-    ) -> Result<Option<types::MarketInstrument>, Box<dyn Error>>
+    ) -> Result<Option<types::MarketInstrument>, Error>
     where
         T: traits::ToInstrumentType + traits::ToFigi,
     {
@@ -248,25 +527,48 @@ where
             enums::InstrumentType::Share => self.share(instrument).await,
             enums::InstrumentType::Currency => self.currency(instrument).await,
             enums::InstrumentType::Future => self.future(instrument).await,
-            // enums::InstrumentType::Option => self.option(instrument).await,
+            enums::InstrumentType::Bond => self.bond(instrument).await,
+            enums::InstrumentType::Etf => self.etf(instrument).await,
+            enums::InstrumentType::Option => {
+                self.option_by(instrument.to_figi().into(), InstrumentIdType::Figi)
+                    .await
+            }
+        }
+    }
+
+    /// Инструмент по FIGI, опцион — по UID.
+    pub async fn market_instrument_by_uid<T>(
+        &self,
+        instrument: T,
+    ) -> Result<Option<types::MarketInstrument>, Error>
+    where
+        T: traits::ToInstrumentType + traits::ToFigi + traits::ToUid,
+    {
+        match instrument.to_instrument_type() {
+            enums::InstrumentType::Option => self.option(instrument).await,
+            _ => self.market_instrument(instrument).await,
         }
     }
 
-    pub async fn shares(&mut self) -> Result<Vec<types::MarketInstrument>, Box<dyn Error>> {
+    pub async fn shares(&self) -> Result<Vec<types::MarketInstrument>, Error> {
         let client = self
             .instruments_service_client
-            .as_mut()
+            .as_ref()
             .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
         let mut request = InstrumentsRequest::default();
         request.set_instrument_status(tinkoff_invest_types::InstrumentStatus::All);
-        let shares = client.shares(request).await?.into_inner().instruments;
+        let shares = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.shares(request).await
+            })
+            .await?
+            .into_inner()
+            .instruments;
         Ok(shares.into_iter().map(|x| x.into()).collect())
     }
 
-    pub async fn share<T>(
-        &mut self,
-        instrument: T,
-    ) -> Result<Option<types::MarketInstrument>, Box<dyn Error>>
+    pub async fn share<T>(&self, instrument: T) -> Result<Option<types::MarketInstrument>, Error>
     where
         T: traits::ToInstrumentType + traits::ToFigi,
     {
@@ -275,32 +577,43 @@ where
         }
         let client = self
             .instruments_service_client
-            .as_mut()
+            .as_ref()
             .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
         let mut request = InstrumentRequest {
             id: instrument.to_figi().into(),
             ..Default::default()
         };
         request.set_id_type(InstrumentIdType::Figi);
-        let share = client.share_by(request).await?.into_inner().instrument;
+        let share = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.share_by(request).await
+            })
+            .await?
+            .into_inner()
+            .instrument;
         Ok(share.as_ref().map(|x| x.clone().into()))
     }
 
-    pub async fn currencies(&mut self) -> Result<Vec<types::MarketInstrument>, Box<dyn Error>> {
+    pub async fn currencies(&self) -> Result<Vec<types::MarketInstrument>, Error> {
         let client = self
             .instruments_service_client
-            .as_mut()
+            .as_ref()
             .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
         let mut request = InstrumentsRequest::default();
         request.set_instrument_status(tinkoff_invest_types::InstrumentStatus::All);
-        let currencies = client.currencies(request).await?.into_inner().instruments;
+        let currencies = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.currencies(request).await
+            })
+            .await?
+            .into_inner()
+            .instruments;
         Ok(currencies.into_iter().map(|v| v.into()).collect())
     }
 
-    pub async fn currency<T>(
-        &mut self,
-        instrument: T,
-    ) -> Result<Option<types::MarketInstrument>, Box<dyn Error>>
+    pub async fn currency<T>(&self, instrument: T) -> Result<Option<types::MarketInstrument>, Error>
     where
         T: traits::ToInstrumentType + traits::ToFigi,
     {
@@ -309,32 +622,43 @@ where
         }
         let client = self
             .instruments_service_client
-            .as_mut()
+            .as_ref()
             .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
         let mut request = InstrumentRequest {
             id: instrument.to_figi().into(),
             ..Default::default()
         };
         request.set_id_type(InstrumentIdType::Figi);
-        let currency = client.currency_by(request).await?.into_inner().instrument;
+        let currency = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.currency_by(request).await
+            })
+            .await?
+            .into_inner()
+            .instrument;
         Ok(currency.as_ref().map(|x| x.clone().into()))
     }
 
-    pub async fn futures(&mut self) -> Result<Vec<types::MarketInstrument>, Box<dyn Error>> {
+    pub async fn futures(&self) -> Result<Vec<types::MarketInstrument>, Error> {
         let client = self
             .instruments_service_client
-            .as_mut()
+            .as_ref()
             .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
         let mut request = InstrumentsRequest::default();
         request.set_instrument_status(tinkoff_invest_types::InstrumentStatus::All);
-        let futures = client.futures(request).await?.into_inner().instruments;
+        let futures = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.futures(request).await
+            })
+            .await?
+            .into_inner()
+            .instruments;
         Ok(futures.into_iter().map(|v| v.into()).collect())
     }
 
-    pub async fn future<T>(
-        &mut self,
-        instrument: T,
-    ) -> Result<Option<types::MarketInstrument>, Box<dyn Error>>
+    pub async fn future<T>(&self, instrument: T) -> Result<Option<types::MarketInstrument>, Error>
     where
         T: traits::ToInstrumentType + traits::ToFigi,
     {
@@ -343,319 +667,1793 @@ where
         }
         let client = self
             .instruments_service_client
-            .as_mut()
+            .as_ref()
             .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
         let mut request = InstrumentRequest {
             id: instrument.to_figi().into(),
             ..Default::default()
         };
         request.set_id_type(InstrumentIdType::Figi);
-        let future = client.future_by(request).await?.into_inner().instrument;
+        let future = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.future_by(request).await
+            })
+            .await?
+            .into_inner()
+            .instrument;
         Ok(future.as_ref().map(|x| x.clone().into()))
     }
 
-    // pub async fn options(&mut self) -> Result<Vec<types::MarketInstrument>, Box<dyn Error>> {
-    //     let client = self
-    //         .instruments_service_client
-    //         .as_mut()
-    //         .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
-    //     let mut request = InstrumentsRequest::default();
-    //     request.set_instrument_status(tinkoff_invest_types::InstrumentStatus::All);
-    //     let futures = client.options(request).await?.into_inner().instruments;
-    //     Ok(futures.into_iter().map(|v| v.into()).collect())
-    // }
-
-    // pub async fn option<T>(
-    //     &mut self,
-    //     instrument: T,
-    // ) -> Result<Option<types::MarketInstrument>, Box<dyn Error>>
-    // where
-    //     T: traits::ToInstrumentType + traits::ToFigi,
-    // {
-    //     if instrument.to_instrument_type() != enums::InstrumentType::Future {
-    //         return Err(TinkoffInvestError::MarketInstrumentTypeNotFuture.into());
-    //     }
-    //     let client = self
-    //         .instruments_service_client
-    //         .as_mut()
-    //         .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
-    //     let mut request = InstrumentRequest {
-    //         id: instrument.to_figi().into(),
-    //         ..Default::default()
-    //     };
-    //     request.set_id_type(InstrumentIdType::Figi);
-    //     let future = client.option_by(request).await?.into_inner().instrument;
-    //     Ok(future.as_ref().map(|x| x.clone().into()))
-    // }
-
-    pub async fn trading_status<T>(
-        &mut self,
-        instrument: T,
-    ) -> Result<enums::TradingStatus, Box<dyn Error>>
-    where
-        T: traits::ToUid,
-    {
+    pub async fn bonds(&self) -> Result<Vec<types::MarketInstrument>, Error> {
         let client = self
-            .market_data_service_client
-            .as_mut()
-            .ok_or(TinkoffInvestError::MarketDataServiceClientNotInit)?;
-        let request = GetTradingStatusRequest {
-            instrument_id: Some(instrument.to_uid().into()),
-            ..Default::default()
-        };
-        Ok(client
-            .get_trading_status(request)
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let mut request = InstrumentsRequest::default();
+        request.set_instrument_status(tinkoff_invest_types::InstrumentStatus::All);
+        let bonds = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.bonds(request).await
+            })
             .await?
             .into_inner()
-            .trading_status()
-            .into())
+            .instruments;
+        Ok(bonds.into_iter().map(|v| v.into()).collect())
     }
 
-    pub async fn candlesticks<T>(
-        &mut self,
-        instrument: T,
-        interval: enums::CandlestickInterval,
-        from: types::DateTime,
-        to: types::DateTime,
-    ) -> Result<Vec<types::Candlestick>, Box<dyn Error>>
+    pub async fn bond<T>(&self, instrument: T) -> Result<Option<types::MarketInstrument>, Error>
     where
-        T: traits::ToUid,
+        T: traits::ToInstrumentType + traits::ToFigi,
     {
-        let uid = instrument.to_uid();
-        let mut request = GetCandlesRequest {
-            instrument_id: Some(uid.clone().into()),
-            from: Some(from.into()),
-            to: Some(to.into()),
+        if instrument.to_instrument_type() != enums::InstrumentType::Bond {
+            return Err(TinkoffInvestError::MarketInstrumentTypeNotBond.into());
+        }
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let mut request = InstrumentRequest {
+            id: instrument.to_figi().into(),
             ..Default::default()
         };
-        request.set_interval(interval.clone().into());
+        request.set_id_type(InstrumentIdType::Figi);
+        let bond = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.bond_by(request).await
+            })
+            .await?
+            .into_inner()
+            .instrument;
+        Ok(bond.as_ref().map(|x| x.clone().into()))
+    }
+
+    pub async fn etfs(&self) -> Result<Vec<types::MarketInstrument>, Error> {
         let client = self
-            .market_data_service_client
-            .as_mut()
-            .ok_or(TinkoffInvestError::MarketDataServiceClientNotInit)?;
-        let candlesticks = client.get_candles(request).await?.into_inner().candles;
-        Ok(candlesticks
-            .into_iter()
-            .map(|x| {
-                let mut candlestick = types::Candlestick::from(x);
-                candlestick.uid = Some(uid.clone());
-                candlestick.interval = Some(interval.clone());
-                candlestick
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let mut request = InstrumentsRequest::default();
+        request.set_instrument_status(tinkoff_invest_types::InstrumentStatus::All);
+        let etfs = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.etfs(request).await
             })
-            .collect())
+            .await?
+            .into_inner()
+            .instruments;
+        Ok(etfs.into_iter().map(|v| v.into()).collect())
     }
 
-    pub async fn orderbook<T>(
-        &mut self,
-        instrument: T,
-        depth: usize,
-    ) -> Result<types::OrderBook, Box<dyn Error>>
+    pub async fn etf<T>(&self, instrument: T) -> Result<Option<types::MarketInstrument>, Error>
     where
-        T: traits::ToUid,
+        T: traits::ToInstrumentType + traits::ToFigi,
     {
-        let request = GetOrderBookRequest {
-            depth: depth as i32,
-            instrument_id: Some(instrument.to_uid().into()),
+        if instrument.to_instrument_type() != enums::InstrumentType::Etf {
+            return Err(TinkoffInvestError::MarketInstrumentTypeNotEtf.into());
+        }
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let mut request = InstrumentRequest {
+            id: instrument.to_figi().into(),
             ..Default::default()
         };
+        request.set_id_type(InstrumentIdType::Figi);
+        let etf = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.etf_by(request).await
+            })
+            .await?
+            .into_inner()
+            .instrument;
+        Ok(etf.as_ref().map(|x| x.clone().into()))
+    }
+
+    pub async fn options(&self) -> Result<Vec<types::MarketInstrument>, Error> {
         let client = self
-            .market_data_service_client
-            .as_mut()
-            .ok_or(TinkoffInvestError::MarketDataServiceClientNotInit)?;
-        Ok(client.get_order_book(request).await?.into_inner().into())
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let mut request = InstrumentsRequest::default();
+        request.set_instrument_status(tinkoff_invest_types::InstrumentStatus::All);
+        #[allow(deprecated)]
+        let options = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.options(request).await
+            })
+            .await?
+            .into_inner()
+            .instruments;
+        Ok(options.into_iter().map(|v| v.into()).collect())
     }
 
-    pub async fn order_on_account<T>(
-        &mut self,
-        account: T,
-        order_id: types::OrderId,
-    ) -> Result<types::Order, Box<dyn Error>>
+    pub async fn option<T>(&self, instrument: T) -> Result<Option<types::MarketInstrument>, Error>
     where
-        T: traits::ToAccountId,
+        T: traits::ToInstrumentType + traits::ToUid,
     {
+        if instrument.to_instrument_type() != enums::InstrumentType::Option {
+            return Err(TinkoffInvestError::MarketInstrumentTypeNotOption.into());
+        }
+        self.option_by(instrument.to_uid().into(), InstrumentIdType::Uid)
+            .await
+    }
+
+    async fn option_by(
+        &self,
+        id: String,
+        id_type: InstrumentIdType,
+    ) -> Result<Option<types::MarketInstrument>, Error> {
         let client = self
-            .orders_service_client
-            .as_mut()
-            .ok_or(TinkoffInvestError::OrdersServiceClientNotInit)?;
-        let request = tinkoff_invest_types::GetOrderStateRequest {
-            account_id: account.to_account_id().into(),
-            order_id: order_id.into(),
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let mut request = InstrumentRequest {
+            id,
             ..Default::default()
         };
-        let order_state = client.get_order_state(request).await?.into_inner();
-        Ok(types::Order::from(order_state))
+        request.set_id_type(id_type);
+        let option = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.option_by(request).await
+            })
+            .await?
+            .into_inner()
+            .instrument;
+        Ok(option.as_ref().map(|x| x.clone().into()))
     }
 
-    pub async fn order<T>(
-        &mut self,
-        order_id: types::OrderId,
-    ) -> Result<types::Order, Box<dyn Error>> {
-        let account = self
-            .account
+    /// Поиск инструментов по строке: тикеру, наименованию, FIGI, ISIN или UID.
+    /// Инструменты без корректного UID пропускаются.
+    pub async fn find_instruments(
+        &self,
+        query: &str,
+        filter: &types::InstrumentsFilter,
+    ) -> Result<Vec<types::InstrumentShort>, Error> {
+        let client = self
+            .instruments_service_client
             .as_ref()
-            .ok_or(TinkoffInvestError::AccountNotSet)?
-            .clone();
-        self.order_on_account(&account, order_id).await
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let request = FindInstrumentRequest {
+            query: query.into(),
+            instrument_kind: filter
+                .instrument_type
+                .clone()
+                .map(|x| tinkoff_invest_types::InstrumentType::from(x) as i32),
+            api_trade_available_flag: filter.is_api_trade_available,
+        };
+        let instruments = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.find_instrument(request).await
+            })
+            .await?
+            .into_inner()
+            .instruments;
+        Ok(instruments
+            .into_iter()
+            .filter_map(|x| x.try_into().ok())
+            .collect())
     }
 
-    #[inline]
-    pub async fn operations_on_account<T, K>(
-        &mut self,
-        account: T,
-        instrument: K,
-        state: enums::OperationState,
-        from: types::DateTime,
-        to: types::DateTime,
-    ) -> Result<Vec<types::Operation>, Box<dyn Error>>
+    /// Опционная доска по базовому активу. `basic_asset_uid` — UID актива
+    /// (`Asset::uid`), а не UID инструмента.
+    pub async fn option_chain(
+        &self,
+        basic_asset_uid: &types::Uid,
+    ) -> Result<types::OptionChain, Error> {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let request = FilterOptionsRequest {
+            basic_asset_uid: Some(basic_asset_uid.clone().into()),
+            ..Default::default()
+        };
+        let options = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.options_by(request).await
+            })
+            .await?
+            .into_inner()
+            .instruments;
+        Ok(types::OptionChain::new(
+            basic_asset_uid.clone(),
+            options.into_iter().map(|v| v.into()).collect(),
+        ))
+    }
+
+    /// Дивиденды по инструменту с датой фиксации реестра в периоде `from`..`to`.
+    pub async fn dividends<T>(
+        &self,
+        instrument: T,
+        from: types::DateTime,
+        to: types::DateTime,
+    ) -> Result<Vec<types::Dividend>, Error>
+    where
+        T: traits::ToUid,
+    {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let request = GetDividendsRequest {
+            instrument_id: instrument.to_uid().into(),
+            from: Some(from.into()),
+            to: Some(to.into()),
+            ..Default::default()
+        };
+        let dividends = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_dividends(request).await
+            })
+            .await?
+            .into_inner()
+            .dividends;
+        Ok(dividends.into_iter().map(|x| x.into()).collect())
+    }
+
+    /// Купоны облигации с датой выплаты в периоде `from`..`to`.
+    pub async fn bond_coupons<T>(
+        &self,
+        instrument: T,
+        from: types::DateTime,
+        to: types::DateTime,
+    ) -> Result<Vec<types::Coupon>, Error>
+    where
+        T: traits::ToUid,
+    {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let request = GetBondCouponsRequest {
+            instrument_id: instrument.to_uid().into(),
+            from: Some(from.into()),
+            to: Some(to.into()),
+            ..Default::default()
+        };
+        let events = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_bond_coupons(request).await
+            })
+            .await?
+            .into_inner()
+            .events;
+        Ok(events.into_iter().map(|x| x.into()).collect())
+    }
+
+    /// События по облигации. Если `kind` не указан, возвращаются события всех типов.
+    pub async fn bond_events<T>(
+        &self,
+        instrument: T,
+        kind: Option<enums::BondEventKind>,
+    ) -> Result<Vec<types::BondEvent>, Error>
+    where
+        T: traits::ToUid,
+    {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let mut request = GetBondEventsRequest {
+            instrument_id: instrument.to_uid().into(),
+            ..Default::default()
+        };
+        request.set_type(kind.unwrap_or(enums::BondEventKind::Unspecified).into());
+        let events = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_bond_events(request).await
+            })
+            .await?
+            .into_inner()
+            .events;
+        Ok(events.into_iter().map(|x| x.into()).collect())
+    }
+
+    /// Накопленный купонный доход по облигации за период `from`..`to`.
+    pub async fn accrued_interests<T>(
+        &self,
+        instrument: T,
+        from: types::DateTime,
+        to: types::DateTime,
+    ) -> Result<Vec<types::AccruedInterest>, Error>
+    where
+        T: traits::ToUid,
+    {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let request = GetAccruedInterestsRequest {
+            instrument_id: instrument.to_uid().into(),
+            from: Some(from.into()),
+            to: Some(to.into()),
+            ..Default::default()
+        };
+        let accrued_interests = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_accrued_interests(request).await
+            })
+            .await?
+            .into_inner()
+            .accrued_interests;
+        Ok(accrued_interests.into_iter().map(|x| x.into()).collect())
+    }
+
+    pub async fn assets(&self) -> Result<Vec<types::Asset>, Error> {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let request = AssetsRequest::default();
+        let assets = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_assets(request).await
+            })
+            .await?
+            .into_inner()
+            .assets;
+        Ok(assets.into_iter().map(|x| x.into()).collect())
+    }
+
+    /// Актив по идентификатору актива, например `MarketInstrument::asset_uid`.
+    pub async fn asset_by(&self, asset_uid: &types::Uid) -> Result<Option<types::Asset>, Error> {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let request = AssetRequest {
+            id: asset_uid.clone().into(),
+        };
+        let asset = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_asset_by(request).await
+            })
+            .await?
+            .into_inner()
+            .asset;
+        Ok(asset.map(|x| x.into()))
+    }
+
+    /// Фундаментальные показатели активов по идентификаторам активов.
+    pub async fn asset_fundamentals(
+        &self,
+        asset_uids: &[types::Uid],
+    ) -> Result<Vec<types::AssetFundamentals>, Error> {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let mut fundamentals = Vec::with_capacity(asset_uids.len());
+        // Не более 100 активов в одном запросе.
+        for chunk in asset_uids.chunks(100) {
+            let request = GetAssetFundamentalsRequest {
+                assets: chunk.iter().map(|x| x.clone().into()).collect(),
+            };
+            let response = self
+                .retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_asset_fundamentals(request).await
+                })
+                .await?
+                .into_inner()
+                .fundamentals;
+            fundamentals.extend(response.into_iter().map(|x| x.into()));
+        }
+        Ok(fundamentals)
+    }
+
+    /// Прогнозы аналитиков и согласованная целевая цена по инструменту.
+    pub async fn forecasts<T>(&self, instrument: T) -> Result<types::Forecast, Error>
+    where
+        T: traits::ToUid,
+    {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let request = GetForecastRequest {
+            instrument_id: instrument.to_uid().into(),
+        };
+        let response = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_forecast_by(request).await
+            })
+            .await?;
+        Ok(response.into_inner().into())
+    }
+
+    async fn favorites_in(&self, group_id: Option<String>) -> Result<Vec<types::Uid>, Error> {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let request = GetFavoritesRequest { group_id };
+        let instruments = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_favorites(request).await
+            })
+            .await?
+            .into_inner()
+            .favorite_instruments;
+        Ok(favorite_uids(instruments))
+    }
+
+    async fn edit_favorites_in<T>(
+        &self,
+        group_id: Option<String>,
+        instruments: &[T],
+        action_type: EditFavoritesActionType,
+    ) -> Result<Vec<types::Uid>, Error>
+    where
+        T: traits::ToUid,
+    {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let mut request = EditFavoritesRequest {
+            instruments: instruments
+                .iter()
+                .map(|x| EditFavoritesRequestInstrument {
+                    instrument_id: x.to_uid().into(),
+                    ..Default::default()
+                })
+                .collect(),
+            group_id,
+            ..Default::default()
+        };
+        request.set_action_type(action_type);
+        let instruments = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.edit_favorites(request).await
+            })
+            .await?
+            .into_inner()
+            .favorite_instruments;
+        Ok(favorite_uids(instruments))
+    }
+
+    /// Избранные инструменты.
+    pub async fn favorites(&self) -> Result<Vec<types::Uid>, Error> {
+        self.favorites_in(None).await
+    }
+
+    /// Добавляет инструменты `add` в избранное и удаляет `remove`.
+    /// Возвращает избранные инструменты после изменения.
+    pub async fn edit_favorites<A, R>(
+        &self,
+        add: &[A],
+        remove: &[R],
+    ) -> Result<Vec<types::Uid>, Error>
+    where
+        A: traits::ToUid,
+        R: traits::ToUid,
+    {
+        if add.is_empty() && remove.is_empty() {
+            return self.favorites().await;
+        }
+        let mut favorites = Vec::new();
+        if !add.is_empty() {
+            favorites = self
+                .edit_favorites_in(None, add, EditFavoritesActionType::Add)
+                .await?;
+        }
+        if !remove.is_empty() {
+            favorites = self
+                .edit_favorites_in(None, remove, EditFavoritesActionType::Del)
+                .await?;
+        }
+        Ok(favorites)
+    }
+
+    pub async fn favorite_groups(&self) -> Result<Vec<types::FavoriteGroup>, Error> {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let request = GetFavoriteGroupsRequest::default();
+        let groups = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_favorite_groups(request).await
+            })
+            .await?
+            .into_inner()
+            .groups;
+        Ok(groups.into_iter().map(|x| x.into()).collect())
+    }
+
+    /// Создаёт группу избранных инструментов. `color` — цвет в формате RGB, например `"AA0000"`.
+    pub async fn create_favorite_group(
+        &self,
+        name: &str,
+        color: &str,
+    ) -> Result<types::FavoriteGroup, Error> {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let request = CreateFavoriteGroupRequest {
+            group_name: name.into(),
+            group_color: color.into(),
+            note: None,
+        };
+        let response = client
+            .clone()
+            .create_favorite_group(request)
+            .await?
+            .into_inner();
+        Ok(types::FavoriteGroup {
+            id: response.group_id,
+            name: response.group_name,
+            color: color.into(),
+            size: 0,
+        })
+    }
+
+    pub async fn delete_favorite_group(&self, group_id: &str) -> Result<(), Error> {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let request = DeleteFavoriteGroupRequest {
+            group_id: group_id.into(),
+        };
+        self.retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.delete_favorite_group(request).await
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Инструменты группы избранного.
+    pub async fn favorite_group_instruments(
+        &self,
+        group_id: &str,
+    ) -> Result<Vec<types::Uid>, Error> {
+        self.favorites_in(Some(group_id.into())).await
+    }
+
+    /// Добавляет инструменты в группу избранного.
+    /// Возвращает инструменты группы после изменения.
+    pub async fn add_to_favorite_group<T>(
+        &self,
+        group_id: &str,
+        instruments: &[T],
+    ) -> Result<Vec<types::Uid>, Error>
+    where
+        T: traits::ToUid,
+    {
+        self.edit_favorites_in(
+            Some(group_id.into()),
+            instruments,
+            EditFavoritesActionType::Add,
+        )
+        .await
+    }
+
+    /// Удаляет инструменты из группы избранного.
+    /// Возвращает инструменты группы после изменения.
+    pub async fn remove_from_favorite_group<T>(
+        &self,
+        group_id: &str,
+        instruments: &[T],
+    ) -> Result<Vec<types::Uid>, Error>
+    where
+        T: traits::ToUid,
+    {
+        self.edit_favorites_in(
+            Some(group_id.into()),
+            instruments,
+            EditFavoritesActionType::Del,
+        )
+        .await
+    }
+
+    /// Гарантийное обеспечение по фьючерсу.
+    pub async fn futures_margin<T>(&self, instrument: T) -> Result<types::FuturesMargin, Error>
+    where
+        T: traits::ToUid,
+    {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let request = GetFuturesMarginRequest {
+            instrument_id: instrument.to_uid().into(),
+            ..Default::default()
+        };
+        let response = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_futures_margin(request).await
+            })
+            .await?;
+        Ok(response.into_inner().into())
+    }
+
+    /// Расписание торгов. Если `exchange` не указан, возвращаются расписания всех торговых площадок.
+    pub async fn trading_schedules(
+        &self,
+        exchange: Option<enums::Exchange>,
+        from: types::DateTime,
+        to: types::DateTime,
+    ) -> Result<Vec<types::TradingSchedule>, Error> {
+        let client = self
+            .instruments_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::InstrumentsServiceClientNotInit)?;
+        let request = TradingSchedulesRequest {
+            exchange: exchange.map(|x| x.into()),
+            from: Some(from.into()),
+            to: Some(to.into()),
+        };
+        let exchanges = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.trading_schedules(request).await
+            })
+            .await?
+            .into_inner()
+            .exchanges;
+        Ok(exchanges.into_iter().map(|x| x.into()).collect())
+    }
+
+    pub async fn trading_status<T>(&self, instrument: T) -> Result<enums::TradingStatus, Error>
+    where
+        T: traits::ToUid,
+    {
+        let client = self
+            .market_data_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::MarketDataServiceClientNotInit)?;
+        let request = GetTradingStatusRequest {
+            instrument_id: Some(instrument.to_uid().into()),
+            ..Default::default()
+        };
+        Ok(self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_trading_status(request).await
+            })
+            .await?
+            .into_inner()
+            .trading_status()
+            .into())
+    }
+
+    pub async fn candlesticks<T>(
+        &self,
+        instrument: T,
+        interval: enums::CandlestickInterval,
+        from: types::DateTime,
+        to: types::DateTime,
+    ) -> Result<Vec<types::Candlestick>, Error>
+    where
+        T: traits::ToUid,
+    {
+        let uid = instrument.to_uid();
+        let mut request = GetCandlesRequest {
+            instrument_id: Some(uid.clone().into()),
+            from: Some(from.into()),
+            to: Some(to.into()),
+            ..Default::default()
+        };
+        request.set_interval(interval.clone().into());
+        let client = self
+            .market_data_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::MarketDataServiceClientNotInit)?;
+        let candlesticks = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_candles(request).await
+            })
+            .await?
+            .into_inner()
+            .candles;
+        Ok(candlesticks
+            .into_iter()
+            .map(|x| {
+                let mut candlestick = types::Candlestick::from(x);
+                candlestick.uid = Some(uid.clone());
+                candlestick.interval = Some(interval.clone());
+                candlestick
+            })
+            .collect())
+    }
+
+    /// Свечи за произвольный период: период разбивается на допустимые для интервала части,
+    /// которые запрашиваются параллельно, не более `concurrency` запросов одновременно.
+    /// Свечи на границах частей не дублируются, результат упорядочен по времени.
+    /// При ошибке любой части остальные запросы отменяются и возвращается эта ошибка.
+    pub async fn candlesticks_range<T>(
+        &self,
+        instrument: T,
+        interval: enums::CandlestickInterval,
+        from: types::DateTime,
+        to: types::DateTime,
+        concurrency: usize,
+    ) -> Result<Vec<types::Candlestick>, Error>
+    where
+        T: traits::ToUid,
+        I: Send + Sync + 'static,
+    {
+        let uid = instrument.to_uid();
+        let concurrency = concurrency.max(1);
+        let mut periods = interval.request_periods(&from, &to).into_iter();
+        let mut join_set = JoinSet::new();
+        let mut candlesticks = Vec::new();
+        loop {
+            while join_set.len() < concurrency {
+                let Some((from, to)) = periods.next() else {
+                    break;
+                };
+                let tinkoff = self.clone();
+                let uid = uid.clone();
+                let interval = interval.clone();
+                join_set.spawn(async move { tinkoff.candlesticks(uid, interval, from, to).await });
+            }
+            let Some(result) = join_set.join_next().await else {
+                break;
+            };
+            match result
+                .map_err(|_| Error::from(TinkoffInvestError::TaskFailed))
+                .and_then(|x| x)
+            {
+                Ok(part) => candlesticks.extend(part),
+                Err(error) => {
+                    join_set.abort_all();
+                    return Err(error);
+                }
+            }
+        }
+        candlesticks.sort_by(|a, b| a.datetime.cmp(&b.datetime));
+        candlesticks.dedup_by(|a, b| a.datetime == b.datetime);
+        Ok(candlesticks)
+    }
+
+    pub async fn orderbook<T>(&self, instrument: T, depth: usize) -> Result<types::OrderBook, Error>
+    where
+        T: traits::ToUid,
+    {
+        let request = GetOrderBookRequest {
+            depth: depth as i32,
+            instrument_id: Some(instrument.to_uid().into()),
+            ..Default::default()
+        };
+        let client = self
+            .market_data_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::MarketDataServiceClientNotInit)?;
+        Ok(self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_order_book(request).await
+            })
+            .await?
+            .into_inner()
+            .into())
+    }
+
+    /// Обезличенные сделки по инструменту за период, не более последнего часа.
+    pub async fn last_trades<T>(
+        &self,
+        instrument: T,
+        from: types::DateTime,
+        to: types::DateTime,
+    ) -> Result<Vec<types::MarketTrade>, Error>
+    where
+        T: traits::ToUid,
+    {
+        let client = self
+            .market_data_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::MarketDataServiceClientNotInit)?;
+        let mut request = GetLastTradesRequest {
+            instrument_id: Some(instrument.to_uid().into()),
+            from: Some(from.into()),
+            to: Some(to.into()),
+            ..Default::default()
+        };
+        request.set_trade_source(enums::TradeSource::All.into());
+        let trades = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_last_trades(request).await
+            })
+            .await?
+            .into_inner()
+            .trades;
+        Ok(trades.into_iter().map(|x| x.into()).collect())
+    }
+
+    /// Цены последних сделок по инструментам.
+    pub async fn last_prices<T>(
+        &self,
+        instruments: &[T],
+    ) -> Result<HashMap<types::Uid, types::LastPrice>, Error>
+    where
+        T: traits::ToUid,
+    {
+        let client = self
+            .market_data_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::MarketDataServiceClientNotInit)?;
+        let request = GetLastPricesRequest {
+            instrument_id: instruments.iter().map(|x| x.to_uid().into()).collect(),
+            ..Default::default()
+        };
+        let last_prices = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_last_prices(request).await
+            })
+            .await?
+            .into_inner()
+            .last_prices;
+        Ok(last_prices
+            .into_iter()
+            .filter_map(|x| Some((x.instrument_uid.parse().ok()?, x.try_into().ok()?)))
+            .collect())
+    }
+
+    /// Цены закрытия торговой сессии по инструментам.
+    pub async fn close_prices<T>(
+        &self,
+        instruments: &[T],
+    ) -> Result<HashMap<types::Uid, types::ClosePrice>, Error>
+    where
+        T: traits::ToUid,
+    {
+        let client = self
+            .market_data_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::MarketDataServiceClientNotInit)?;
+        let request = GetClosePricesRequest {
+            instruments: instruments
+                .iter()
+                .map(|x| InstrumentClosePriceRequest {
+                    instrument_id: x.to_uid().into(),
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let close_prices = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_close_prices(request).await
+            })
+            .await?
+            .into_inner()
+            .close_prices;
+        Ok(close_prices
+            .into_iter()
+            .filter_map(|x| Some((x.instrument_uid.parse().ok()?, x.try_into().ok()?)))
+            .collect())
+    }
+
+    pub async fn order_on_account<T>(
+        &self,
+        account: T,
+        order_id: types::OrderId,
+    ) -> Result<types::Order, Error>
+    where
+        T: traits::ToAccountId,
+    {
+        let request = tinkoff_invest_types::GetOrderStateRequest {
+            account_id: account.to_account_id().into(),
+            order_id: order_id.into(),
+            ..Default::default()
+        };
+        let order_state = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_sandbox_order_state(request).await
+                })
+                .await?
+                .into_inner()
+        } else {
+            let client = self
+                .orders_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::OrdersServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_order_state(request).await
+                })
+                .await?
+                .into_inner()
+        };
+        Ok(types::Order::from(order_state))
+    }
+
+    pub async fn order<T>(&self, order_id: types::OrderId) -> Result<types::Order, Error> {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.order_on_account(&account, order_id).await
+    }
+
+    pub async fn orders_on_account<T>(&self, account: T) -> Result<Vec<types::Order>, Error>
+    where
+        T: traits::ToAccountId,
+    {
+        let request = GetOrdersRequest {
+            account_id: account.to_account_id().into(),
+            ..Default::default()
+        };
+        let response = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_sandbox_orders(request).await
+                })
+                .await?
+        } else {
+            let client = self
+                .orders_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::OrdersServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_orders(request).await
+                })
+                .await?
+        };
+        let orders = response.into_inner().orders;
+        Ok(orders.into_iter().map(|x| x.into()).collect())
+    }
+
+    pub async fn orders(&self) -> Result<Vec<types::Order>, Error> {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.orders_on_account(&account).await
+    }
+
+    #[inline]
+    pub async fn operations_on_account<T, K>(
+        &self,
+        account: T,
+        instrument: K,
+        state: enums::OperationState,
+        from: types::DateTime,
+        to: types::DateTime,
+    ) -> Result<Vec<types::Operation>, Error>
+    where
+        T: traits::ToAccountId,
+        K: traits::ToFigi,
+    {
+        let from = Some(from.into());
+        let to = Some(to.into());
+        let mut request = OperationsRequest {
+            account_id: account.to_account_id().into(),
+            figi: Some(instrument.to_figi().into()),
+            state: Some(0),
+            from,
+            to,
+        };
+        request.set_state(state.into());
+        let operations = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_sandbox_operations(request).await
+                })
+                .await?
+                .into_inner()
+                .operations
+        } else {
+            let client = self
+                .operations_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::OperationsServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_operations(request).await
+                })
+                .await?
+                .into_inner()
+                .operations
+        };
+        Ok(operations.into_iter().map(|x| x.into()).collect())
+    }
+
+    pub async fn operations<T>(
+        &self,
+        instrument: T,
+        state: enums::OperationState,
+        from: types::DateTime,
+        to: types::DateTime,
+    ) -> Result<Vec<types::Operation>, Error>
+    where
+        T: traits::ToFigi,
+    {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.operations_on_account(&account, instrument, state, from, to)
+            .await
+    }
+
+    async fn operations_by_cursor_page(
+        &self,
+        request: GetOperationsByCursorRequest,
+    ) -> Result<GetOperationsByCursorResponse, Error> {
+        let response = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_sandbox_operations_by_cursor(request).await
+                })
+                .await?
+        } else {
+            let client = self
+                .operations_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::OperationsServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_operations_by_cursor(request).await
+                })
+                .await?
+        };
+        Ok(response.into_inner())
+    }
+
+    /// Операции по счёту, получаемые постранично через `GetOperationsByCursor`.
+    /// Следующая страница запрашивается по мере чтения потока.
+    pub fn operations_by_cursor_on_account<T>(
+        &self,
+        account: T,
+        filter: types::OperationsFilter,
+    ) -> Result<ReceiverStream<Result<types::Operation, Error>>, Error>
+    where
+        T: traits::ToAccountId,
+        I: Send + Sync + 'static,
+    {
+        if self.sandbox_service_client.is_none() && self.operations_service_client.is_none() {
+            return Err(TinkoffInvestError::OperationsServiceClientNotInit.into());
+        }
+        let page_size = filter.page_size.clamp(1, 1000);
+        let mut request = GetOperationsByCursorRequest {
+            account_id: account.to_account_id().into(),
+            instrument_id: filter.instrument_uid.map(|x| x.into()),
+            from: filter.from.map(|x| x.into()),
+            to: filter.to.map(|x| x.into()),
+            limit: Some(page_size as i32),
+            operation_types: filter
+                .operation_types
+                .into_iter()
+                .map(|x| tinkoff_invest_types::OperationType::from(x) as i32)
+                .collect(),
+            state: filter
+                .state
+                .map(|x| tinkoff_invest_types::OperationState::from(x) as i32),
+            without_commissions: Some(!filter.with_commissions),
+            without_trades: Some(!filter.with_trades),
+            ..Default::default()
+        };
+        let (sender, receiver) = mpsc::channel(page_size as usize);
+        let tinkoff = self.clone();
+        tokio::spawn(async move {
+            loop {
+                match tinkoff.operations_by_cursor_page(request.clone()).await {
+                    Ok(response) => {
+                        for item in response.items {
+                            if sender.send(Ok(item.into())).await.is_err() {
+                                return;
+                            }
+                        }
+                        if !response.has_next || response.next_cursor.is_empty() {
+                            return;
+                        }
+                        request.cursor = Some(response.next_cursor);
+                    }
+                    Err(error) => {
+                        let _ = sender.send(Err(error)).await;
+                        return;
+                    }
+                }
+            }
+        });
+        Ok(ReceiverStream::new(receiver))
+    }
+
+    pub fn operations_by_cursor(
+        &self,
+        filter: types::OperationsFilter,
+    ) -> Result<ReceiverStream<Result<types::Operation, Error>>, Error>
+    where
+        I: Send + Sync + 'static,
+    {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?;
+        self.operations_by_cursor_on_account(account, filter)
+    }
+
+    /// Запрос формирования отчёта не повторяется, повторяются только запросы страниц.
+    async fn broker_report_payload(
+        &self,
+        payload: broker_report_request::Payload,
+    ) -> Result<Option<broker_report_response::Payload>, Error> {
+        let client = self
+            .operations_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::OperationsServiceClientNotInit)?;
+        let generate = matches!(
+            payload,
+            broker_report_request::Payload::GenerateBrokerReportRequest(_)
+        );
+        let request = BrokerReportRequest {
+            payload: Some(payload),
+        };
+        let response = if generate {
+            client.clone().get_broker_report(request).await?
+        } else {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_broker_report(request).await
+                })
+                .await?
+        };
+        Ok(response.into_inner().payload)
+    }
+
+    /// Брокерский отчёт за период. Отчёт формируется асинхронно:
+    /// метод запускает формирование, ожидает готовности и собирает все страницы.
+    pub async fn broker_report_on_account<T>(
+        &self,
+        account: T,
+        from: types::DateTime,
+        to: types::DateTime,
+    ) -> Result<Vec<types::BrokerReportItem>, Error>
+    where
+        T: traits::ToAccountId,
+    {
+        let payload = broker_report_request::Payload::GenerateBrokerReportRequest(
+            GenerateBrokerReportRequest {
+                account_id: account.to_account_id().into(),
+                from: Some(from.into()),
+                to: Some(to.into()),
+            },
+        );
+        let task_id = match self.broker_report_payload(payload).await? {
+            Some(broker_report_response::Payload::GenerateBrokerReportResponse(x))
+                if !x.task_id.is_empty() =>
+            {
+                x.task_id
+            }
+            _ => return Err(TinkoffInvestError::ReportTaskIdNotSet.into()),
+        };
+        let mut items = Vec::new();
+        let mut page = 0;
+        loop {
+            let response = poll_report(|| {
+                let payload = broker_report_request::Payload::GetBrokerReportRequest(
+                    GetBrokerReportRequest {
+                        task_id: task_id.clone(),
+                        page: Some(page),
+                    },
+                );
+                async move {
+                    match self.broker_report_payload(payload).await? {
+                        Some(broker_report_response::Payload::GetBrokerReportResponse(x)) => {
+                            Ok(Some(x))
+                        }
+                        _ => Ok(None),
+                    }
+                }
+            })
+            .await?;
+            items.extend(response.broker_report.into_iter().map(|x| x.into()));
+            page += 1;
+            if page >= response.pages_count {
+                break;
+            }
+        }
+        Ok(items)
+    }
+
+    pub async fn broker_report(
+        &self,
+        from: types::DateTime,
+        to: types::DateTime,
+    ) -> Result<Vec<types::BrokerReportItem>, Error> {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.broker_report_on_account(&account, from, to).await
+    }
+
+    /// Запрос формирования отчёта не повторяется, повторяются только запросы страниц.
+    async fn dividends_foreign_issuer_payload(
+        &self,
+        payload: get_dividends_foreign_issuer_request::Payload,
+    ) -> Result<Option<get_dividends_foreign_issuer_response::Payload>, Error> {
+        let client = self
+            .operations_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::OperationsServiceClientNotInit)?;
+        let generate = matches!(
+            payload,
+            get_dividends_foreign_issuer_request::Payload::GenerateDivForeignIssuerReport(_)
+        );
+        let request = GetDividendsForeignIssuerRequest {
+            payload: Some(payload),
+        };
+        let response = if generate {
+            client.clone().get_dividends_foreign_issuer(request).await?
+        } else {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_dividends_foreign_issuer(request).await
+                })
+                .await?
+        };
+        Ok(response.into_inner().payload)
+    }
+
+    /// Отчёт «Справка о доходах за пределами РФ» за период.
+    /// Формируется асинхронно, как и брокерский отчёт.
+    pub async fn dividends_foreign_issuer_report_on_account<T>(
+        &self,
+        account: T,
+        from: types::DateTime,
+        to: types::DateTime,
+    ) -> Result<Vec<types::DividendForeignIssuerItem>, Error>
+    where
+        T: traits::ToAccountId,
+    {
+        let payload = get_dividends_foreign_issuer_request::Payload::GenerateDivForeignIssuerReport(
+            GenerateDividendsForeignIssuerReportRequest {
+                account_id: account.to_account_id().into(),
+                from: Some(from.into()),
+                to: Some(to.into()),
+            },
+        );
+        let task_id = match self.dividends_foreign_issuer_payload(payload).await? {
+            Some(
+                get_dividends_foreign_issuer_response::Payload::GenerateDivForeignIssuerReportResponse(
+                    x,
+                ),
+            ) if !x.task_id.is_empty() => x.task_id,
+            _ => return Err(TinkoffInvestError::ReportTaskIdNotSet.into()),
+        };
+        let mut items = Vec::new();
+        let mut page = 0;
+        loop {
+            let response =
+                poll_report(|| {
+                    let payload =
+                        get_dividends_foreign_issuer_request::Payload::GetDivForeignIssuerReport(
+                            GetDividendsForeignIssuerReportRequest {
+                                task_id: task_id.clone(),
+                                page: Some(page),
+                            },
+                        );
+                    async move {
+                        match self.dividends_foreign_issuer_payload(payload).await? {
+                        Some(get_dividends_foreign_issuer_response::Payload::DivForeignIssuerReport(
+                            x,
+                        )) => Ok(Some(x)),
+                        _ => Ok(None),
+                    }
+                    }
+                })
+                .await?;
+            items.extend(
+                response
+                    .dividends_foreign_issuer_report
+                    .into_iter()
+                    .map(|x| x.into()),
+            );
+            page += 1;
+            if page >= response.pages_count {
+                break;
+            }
+        }
+        Ok(items)
+    }
+
+    pub async fn dividends_foreign_issuer_report(
+        &self,
+        from: types::DateTime,
+        to: types::DateTime,
+    ) -> Result<Vec<types::DividendForeignIssuerItem>, Error> {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.dividends_foreign_issuer_report_on_account(&account, from, to)
+            .await
+    }
+
+    /// Портфель по счёту. Суммы пересчитываются в `currency`: поддерживаются RUB, USD и EUR.
+    pub async fn portfolio_on_account<T>(
+        &self,
+        account: T,
+        currency: enums::Currency,
+    ) -> Result<types::Portfolio, Error>
+    where
+        T: traits::ToAccountId,
+    {
+        let currency = match currency {
+            enums::Currency::RUB => CurrencyRequest::Rub,
+            enums::Currency::USD => CurrencyRequest::Usd,
+            enums::Currency::EUR => CurrencyRequest::Eur,
+            _ => return Err(TinkoffInvestError::CurrencyNotSupported.into()),
+        };
+        let mut request = PortfolioRequest {
+            account_id: account.to_account_id().into(),
+            ..Default::default()
+        };
+        request.set_currency(currency);
+        let portfolio = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_sandbox_portfolio(request).await
+                })
+                .await?
+                .into_inner()
+        } else {
+            let client = self
+                .operations_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::OperationsServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_portfolio(request).await
+                })
+                .await?
+                .into_inner()
+        };
+        Ok(portfolio.into())
+    }
+
+    pub async fn portfolio(&self, currency: enums::Currency) -> Result<types::Portfolio, Error> {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.portfolio_on_account(&account, currency).await
+    }
+
+    pub async fn positions_on_account<T>(&self, account: T) -> Result<types::Positions, Error>
+    where
+        T: traits::ToAccountId,
+    {
+        let request = PositionsRequest {
+            account_id: account.to_account_id().into(),
+        };
+        let response = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_sandbox_positions(request).await
+                })
+                .await?
+        } else {
+            let client = self
+                .operations_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::OperationsServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_positions(request).await
+                })
+                .await?
+        };
+        let positions = response.into_inner().into();
+        Ok(positions)
+    }
+
+    pub async fn positions(&self) -> Result<types::Positions, Error> {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.positions_on_account(&account).await
+    }
+
+    pub async fn withdraw_limits_on_account<T>(
+        &self,
+        account: T,
+    ) -> Result<types::WithdrawLimits, Error>
+    where
+        T: traits::ToAccountId,
+    {
+        let request = WithdrawLimitsRequest {
+            account_id: account.to_account_id().into(),
+        };
+        let response = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_sandbox_withdraw_limits(request).await
+                })
+                .await?
+        } else {
+            let client = self
+                .operations_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::OperationsServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_withdraw_limits(request).await
+                })
+                .await?
+        };
+        Ok(response.into_inner().into())
+    }
+
+    pub async fn withdraw_limits(&self) -> Result<types::WithdrawLimits, Error> {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.withdraw_limits_on_account(&account).await
+    }
+
+    pub async fn margin_attributes_on_account<T>(
+        &self,
+        account: T,
+    ) -> Result<types::MarginAttributes, Error>
+    where
+        T: traits::ToAccountId,
+    {
+        let client = self
+            .users_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::UsersServiceClientNotInit)?;
+        let request = GetMarginAttributesRequest {
+            account_id: account.to_account_id().into(),
+        };
+        let response = self
+            .retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.get_margin_attributes(request).await
+            })
+            .await?;
+        Ok(response.into_inner().into())
+    }
+
+    pub async fn margin_attributes(&self) -> Result<types::MarginAttributes, Error> {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.margin_attributes_on_account(&account).await
+    }
+
+    /// Максимальное количество лотов для покупки и продажи.
+    /// Если `price` не указан, расчёт ведётся по текущей цене.
+    pub async fn max_lots_on_account<T, K>(
+        &self,
+        account: T,
+        instrument: K,
+        price: Option<types::MoneyValue>,
+    ) -> Result<types::MaxLots, Error>
+    where
+        T: traits::ToAccountId,
+        K: traits::ToUid,
+    {
+        let request = GetMaxLotsRequest {
+            account_id: account.to_account_id().into(),
+            instrument_id: instrument.to_uid().into(),
+            price: price.map(|x| x.into()),
+        };
+        let response = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_sandbox_max_lots(request).await
+                })
+                .await?
+        } else {
+            let client = self
+                .orders_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::OrdersServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_max_lots(request).await
+                })
+                .await?
+        };
+        Ok(response.into_inner().into())
+    }
+
+    pub async fn max_lots<T>(
+        &self,
+        instrument: T,
+        price: Option<types::MoneyValue>,
+    ) -> Result<types::MaxLots, Error>
+    where
+        T: traits::ToUid,
+    {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.max_lots_on_account(&account, instrument, price).await
+    }
+
+    /// Предварительная стоимость заявки с учётом комиссий.
+    pub async fn order_price_on_account<T, K>(
+        &self,
+        account: T,
+        instrument: K,
+        price: types::MoneyValue,
+        direction: enums::OrderDirection,
+        lots: u64,
+    ) -> Result<types::OrderPrice, Error>
+    where
+        T: traits::ToAccountId,
+        K: traits::ToUid,
+    {
+        let mut request = GetOrderPriceRequest {
+            account_id: account.to_account_id().into(),
+            instrument_id: instrument.to_uid().into(),
+            price: Some(price.into()),
+            quantity: lots as i64,
+            ..Default::default()
+        };
+        request.set_direction(direction.into());
+        let response = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_sandbox_order_price(request).await
+                })
+                .await?
+        } else {
+            let client = self
+                .orders_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::OrdersServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_order_price(request).await
+                })
+                .await?
+        };
+        Ok(response.into_inner().into())
+    }
+
+    pub async fn order_price<T>(
+        &self,
+        instrument: T,
+        price: types::MoneyValue,
+        direction: enums::OrderDirection,
+        lots: u64,
+    ) -> Result<types::OrderPrice, Error>
+    where
+        T: traits::ToUid,
+    {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.order_price_on_account(&account, instrument, price, direction, lots)
+            .await
+    }
+
+    #[inline]
+    pub async fn post_order_on_account<T, K>(
+        &self,
+        account: T,
+        instrument: K,
+        order: types::OrderRequest,
+        order_id: Option<String>,
+    ) -> Result<types::Order, Error>
     where
         T: traits::ToAccountId,
-        K: traits::ToFigi,
+        K: traits::ToUid,
     {
-        let from = Some(from.into());
-        let to = Some(to.into());
-        let client = self
-            .operations_service_client
-            .as_mut()
-            .ok_or(TinkoffInvestError::OperationsServiceClientNotInit)?;
-        let mut request = OperationsRequest {
+        let order_id = order_id.unwrap_or_else(|| Uuid::new_v4().to_string());
+        let mut request = PostOrderRequest {
+            order_id,
             account_id: account.to_account_id().into(),
-            figi: Some(instrument.to_figi().into()),
-            state: Some(0),
-            from,
-            to,
+            instrument_id: instrument.to_uid().into(),
+            quantity: order.lots as i64,
+            price: order.price.map(|x| x.into()),
+            ..Default::default()
         };
-        request.set_state(state.into());
-        let operations = client
-            .get_operations(request)
-            .await?
-            .into_inner()
-            .operations;
-        Ok(operations.into_iter().map(|x| x.into()).collect())
+        request.set_direction(order.direction.into());
+        request.set_order_type(order.kind.into());
+        request.set_time_in_force(order.time_in_force.into());
+        request.set_price_type(order.price_type.into());
+        let response = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.post_sandbox_order(request).await
+                })
+                .await?
+        } else {
+            let client = self
+                .orders_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::OrdersServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.post_order(request).await
+                })
+                .await?
+        };
+        Ok(response.into_inner().into())
     }
 
-    pub async fn operations<T>(
-        &mut self,
+    pub async fn post_order<T>(
+        &self,
         instrument: T,
-        state: enums::OperationState,
-        from: types::DateTime,
-        to: types::DateTime,
-    ) -> Result<Vec<types::Operation>, Box<dyn Error>>
+        order: types::OrderRequest,
+        order_id: Option<String>,
+    ) -> Result<types::Order, Error>
     where
-        T: traits::ToFigi,
+        T: traits::ToUid,
     {
         let account = self
             .account
             .as_ref()
             .ok_or(TinkoffInvestError::AccountNotSet)?
             .clone();
-        self.operations_on_account(&account, instrument, state, from, to)
+        self.post_order_on_account(&account, instrument, order, order_id)
             .await
     }
 
-    pub async fn portfolio_on_account<T>(
-        &mut self,
+    #[inline]
+    pub async fn limit_order_on_account<T, K>(
+        &self,
         account: T,
-    ) -> Result<Vec<types::PortfolioPosition>, Box<dyn Error>>
+        instrument: K,
+        direction: enums::OrderDirection,
+        quantity: u64,
+        price: types::MoneyValue,
+        order_id: Option<String>,
+    ) -> Result<types::Order, Error>
     where
         T: traits::ToAccountId,
+        K: traits::ToUid,
     {
-        let mut request = PortfolioRequest {
-            account_id: account.to_account_id().into(),
-            ..Default::default()
-        };
-        request.set_currency(CurrencyRequest::Rub);
-        let client = self
-            .operations_service_client
-            .as_mut()
-            .ok_or(TinkoffInvestError::OperationsServiceClientNotInit)?;
-        let portfolio_positions = client
-            .get_portfolio(request)
-            .await?
-            .into_inner()
-            .positions
-            .iter()
-            .map(|x| x.into())
-            .collect();
-        Ok(portfolio_positions)
+        let order = types::OrderRequest::limit(direction, quantity, price);
+        self.post_order_on_account(account, instrument, order, order_id)
+            .await
     }
 
-    pub async fn portfolio(&mut self) -> Result<Vec<types::PortfolioPosition>, Box<dyn Error>> {
+    pub async fn limit_order<T>(
+        &self,
+        instrument: T,
+        direction: enums::OrderDirection,
+        quantity: u64,
+        price: types::MoneyValue,
+        order_id: Option<String>,
+    ) -> Result<types::Order, Error>
+    where
+        T: traits::ToUid,
+    {
         let account = self
             .account
             .as_ref()
             .ok_or(TinkoffInvestError::AccountNotSet)?
             .clone();
-        self.portfolio_on_account(&account).await
+        self.limit_order_on_account(&account, instrument, direction, quantity, price, order_id)
+            .await
     }
 
-    pub async fn positions_on_account<T>(
-        &mut self,
+    #[inline]
+    pub async fn market_order_on_account<T, K>(
+        &self,
         account: T,
-    ) -> Result<types::Positions, Box<dyn Error>>
+        instrument: K,
+        direction: enums::OrderDirection,
+        quantity: u64,
+        order_id: Option<String>,
+    ) -> Result<types::Order, Error>
     where
         T: traits::ToAccountId,
+        K: traits::ToUid,
     {
-        let request = PositionsRequest {
-            account_id: account.to_account_id().into(),
-        };
-        let client = self
-            .operations_service_client
-            .as_mut()
-            .ok_or(TinkoffInvestError::OperationsServiceClientNotInit)?;
-        let response = client.get_positions(request).await?;
-        let positions = response.into_inner().into();
-        Ok(positions)
+        let order = types::OrderRequest::market(direction, quantity);
+        self.post_order_on_account(account, instrument, order, order_id)
+            .await
     }
 
-    pub async fn positions(&mut self) -> Result<types::Positions, Box<dyn Error>> {
+    pub async fn market_order<T>(
+        &self,
+        instrument: T,
+        direction: enums::OrderDirection,
+        quantity: u64,
+        order_id: Option<String>,
+    ) -> Result<types::Order, Error>
+    where
+        T: traits::ToUid,
+    {
         let account = self
             .account
             .as_ref()
             .ok_or(TinkoffInvestError::AccountNotSet)?
             .clone();
-        self.positions_on_account(&account).await
+        self.market_order_on_account(&account, instrument, direction, quantity, order_id)
+            .await
     }
 
     #[inline]
-    pub async fn limit_order_on_account<T, K>(
-        &mut self,
+    pub async fn best_price_order_on_account<T, K>(
+        &self,
         account: T,
         instrument: K,
         direction: enums::OrderDirection,
         quantity: u64,
-        price: types::MoneyValue,
         order_id: Option<String>,
-    ) -> Result<types::Order, Box<dyn Error>>
+    ) -> Result<types::Order, Error>
     where
         T: traits::ToAccountId,
         K: traits::ToUid,
     {
-        let order_id = order_id.unwrap_or_else(|| Uuid::new_v4().to_string());
-        let mut request = PostOrderRequest {
-            order_id,
-            account_id: account.to_account_id().into(),
-            instrument_id: instrument.to_uid().into(),
-            quantity: quantity as i64,
-            price: Some(price.into()),
-            ..Default::default()
-        };
-        request.set_direction(direction.into());
-        request.set_order_type(tinkoff_invest_types::OrderType::Limit);
-        let client = self
-            .orders_service_client
-            .as_mut()
-            .ok_or(TinkoffInvestError::OrdersServiceClientNotInit)?;
-        Ok(client.post_order(request).await?.into_inner().into())
+        let order = types::OrderRequest::best_price(direction, quantity);
+        self.post_order_on_account(account, instrument, order, order_id)
+            .await
     }
 
-    pub async fn limit_order<T>(
-        &mut self,
+    pub async fn best_price_order<T>(
+        &self,
         instrument: T,
         direction: enums::OrderDirection,
         quantity: u64,
-        price: types::MoneyValue,
         order_id: Option<String>,
-    ) -> Result<types::Order, Box<dyn Error>>
+    ) -> Result<types::Order, Error>
     where
         T: traits::ToUid,
     {
@@ -664,16 +2462,17 @@ where
             .as_ref()
             .ok_or(TinkoffInvestError::AccountNotSet)?
             .clone();
-        self.limit_order_on_account(&account, instrument, direction, quantity, price, order_id)
+        self.best_price_order_on_account(&account, instrument, direction, quantity, order_id)
             .await
     }
 
     #[inline]
     pub async fn cancel_order_on_account<T, K>(
-        &mut self,
+        &self,
         account: T,
         order: K,
-    ) -> Result<Option<types::DateTime>, Box<dyn Error>>
+        order_id_type: enums::OrderIdType,
+    ) -> Result<Option<types::DateTime>, Error>
     where
         T: traits::ToAccountId,
         K: traits::ToOrderId,
@@ -683,23 +2482,251 @@ where
             order_id: order.to_order_id().into(),
             ..Default::default()
         };
-        request.set_order_id_type(OrderIdType::Exchange);
-        let client = self
-            .orders_service_client
-            .as_mut()
-            .ok_or(TinkoffInvestError::OrdersServiceClientNotInit)?;
-        Ok(client
-            .cancel_order(request)
-            .await?
-            .into_inner()
-            .time
-            .map(|x| x.into()))
+        request.set_order_id_type(order_id_type.into());
+        let response = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.cancel_sandbox_order(request).await
+                })
+                .await?
+        } else {
+            let client = self
+                .orders_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::OrdersServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.cancel_order(request).await
+                })
+                .await?
+        };
+        Ok(response.into_inner().time.map(|x| x.into()))
     }
 
     pub async fn cancel_order<T>(
-        &mut self,
+        &self,
+        order: T,
+        order_id_type: enums::OrderIdType,
+    ) -> Result<Option<types::DateTime>, Error>
+    where
+        T: traits::ToOrderId,
+    {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.cancel_order_on_account(&account, order, order_id_type)
+            .await
+    }
+
+    #[inline]
+    pub async fn replace_order_on_account<T, K>(
+        &self,
+        account: T,
+        order: K,
+        quantity: u64,
+        price: types::MoneyValue,
+        idempotency_key: Option<String>,
+    ) -> Result<types::Order, Error>
+    where
+        T: traits::ToAccountId,
+        K: traits::ToOrderId,
+    {
+        let idempotency_key = idempotency_key.unwrap_or_else(|| Uuid::new_v4().to_string());
+        let request = ReplaceOrderRequest {
+            account_id: account.to_account_id().into(),
+            order_id: order.to_order_id().into(),
+            idempotency_key,
+            quantity: quantity as i64,
+            price: Some(price.into()),
+            ..Default::default()
+        };
+        let response = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.replace_sandbox_order(request).await
+                })
+                .await?
+        } else {
+            let client = self
+                .orders_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::OrdersServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.replace_order(request).await
+                })
+                .await?
+        };
+        Ok(response.into_inner().into())
+    }
+
+    pub async fn replace_order<T>(
+        &self,
         order: T,
-    ) -> Result<Option<types::DateTime>, Box<dyn Error>>
+        quantity: u64,
+        price: types::MoneyValue,
+        idempotency_key: Option<String>,
+    ) -> Result<types::Order, Error>
+    where
+        T: traits::ToOrderId,
+    {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.replace_order_on_account(&account, order, quantity, price, idempotency_key)
+            .await
+    }
+
+    pub async fn post_stop_order_on_account<T, K>(
+        &self,
+        account: T,
+        instrument: K,
+        stop_order: types::StopOrderRequest,
+        order_id: Option<String>,
+    ) -> Result<types::OrderId, Error>
+    where
+        T: traits::ToAccountId,
+        K: traits::ToUid,
+    {
+        let order_id = order_id.unwrap_or_else(|| Uuid::new_v4().to_string());
+        let mut request = PostStopOrderRequest {
+            order_id,
+            account_id: account.to_account_id().into(),
+            instrument_id: instrument.to_uid().into(),
+            quantity: stop_order.lots as i64,
+            price: stop_order.price.map(|x| x.into()),
+            stop_price: Some(stop_order.stop_price.into()),
+            expire_date: stop_order.expire_date.map(|x| x.into()),
+            ..Default::default()
+        };
+        request.set_direction(stop_order.direction.into());
+        request.set_stop_order_type(stop_order.kind.into());
+        request.set_expiration_type(stop_order.expiration_type.into());
+        let response = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.post_sandbox_stop_order(request).await
+                })
+                .await?
+        } else {
+            let client = self
+                .stop_orders_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::StopOrdersServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.post_stop_order(request).await
+                })
+                .await?
+        };
+        Ok(response.into_inner().stop_order_id.into())
+    }
+
+    pub async fn post_stop_order<T>(
+        &self,
+        instrument: T,
+        stop_order: types::StopOrderRequest,
+        order_id: Option<String>,
+    ) -> Result<types::OrderId, Error>
+    where
+        T: traits::ToUid,
+    {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.post_stop_order_on_account(&account, instrument, stop_order, order_id)
+            .await
+    }
+
+    pub async fn get_stop_orders_on_account<T>(
+        &self,
+        account: T,
+        status: enums::StopOrderStatus,
+    ) -> Result<Vec<types::StopOrder>, Error>
+    where
+        T: traits::ToAccountId,
+    {
+        let mut request = GetStopOrdersRequest {
+            account_id: account.to_account_id().into(),
+            ..Default::default()
+        };
+        request.set_status(status.into());
+        let response = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_sandbox_stop_orders(request).await
+                })
+                .await?
+        } else {
+            let client = self
+                .stop_orders_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::StopOrdersServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.get_stop_orders(request).await
+                })
+                .await?
+        };
+        let stop_orders = response.into_inner().stop_orders;
+        Ok(stop_orders.into_iter().map(|x| x.into()).collect())
+    }
+
+    pub async fn get_stop_orders(
+        &self,
+        status: enums::StopOrderStatus,
+    ) -> Result<Vec<types::StopOrder>, Error> {
+        let account = self
+            .account
+            .as_ref()
+            .ok_or(TinkoffInvestError::AccountNotSet)?
+            .clone();
+        self.get_stop_orders_on_account(&account, status).await
+    }
+
+    pub async fn cancel_stop_order_on_account<T, K>(
+        &self,
+        account: T,
+        stop_order: K,
+    ) -> Result<Option<types::DateTime>, Error>
+    where
+        T: traits::ToAccountId,
+        K: traits::ToOrderId,
+    {
+        let request = CancelStopOrderRequest {
+            account_id: account.to_account_id().into(),
+            stop_order_id: stop_order.to_order_id().into(),
+        };
+        let response = if let Some(client) = self.sandbox_service_client.as_ref() {
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.cancel_sandbox_stop_order(request).await
+                })
+                .await?
+        } else {
+            let client = self
+                .stop_orders_service_client
+                .as_ref()
+                .ok_or(TinkoffInvestError::StopOrdersServiceClientNotInit)?;
+            self.retry_policy
+                .call(client, request, |mut client, request| async move {
+                    client.cancel_stop_order(request).await
+                })
+                .await?
+        };
+        Ok(response.into_inner().time.map(|x| x.into()))
+    }
+
+    pub async fn cancel_stop_order<T>(
+        &self,
+        stop_order: T,
+    ) -> Result<Option<types::DateTime>, Error>
     where
         T: traits::ToOrderId,
     {
@@ -708,6 +2735,97 @@ where
             .as_ref()
             .ok_or(TinkoffInvestError::AccountNotSet)?
             .clone();
-        self.cancel_order_on_account(&account, order).await
+        self.cancel_stop_order_on_account(&account, stop_order)
+            .await
+    }
+
+    pub async fn open_sandbox_account(
+        &self,
+        name: Option<String>,
+    ) -> Result<types::AccountId, Error> {
+        let client = self
+            .sandbox_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::SandboxServiceClientNotInit)?;
+        let request = OpenSandboxAccountRequest { name };
+        let account_id = client
+            .clone()
+            .open_sandbox_account(request)
+            .await?
+            .into_inner()
+            .account_id;
+        Ok(account_id.into())
+    }
+
+    pub async fn close_sandbox_account<T>(&self, account: T) -> Result<(), Error>
+    where
+        T: traits::ToAccountId,
+    {
+        let client = self
+            .sandbox_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::SandboxServiceClientNotInit)?;
+        let request = CloseSandboxAccountRequest {
+            account_id: account.to_account_id().into(),
+        };
+        self.retry_policy
+            .call(client, request, |mut client, request| async move {
+                client.close_sandbox_account(request).await
+            })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn sandbox_pay_in<T>(
+        &self,
+        account: T,
+        amount: types::Money,
+    ) -> Result<Option<types::Money>, Error>
+    where
+        T: traits::ToAccountId,
+    {
+        let client = self
+            .sandbox_service_client
+            .as_ref()
+            .ok_or(TinkoffInvestError::SandboxServiceClientNotInit)?;
+        let request = SandboxPayInRequest {
+            account_id: account.to_account_id().into(),
+            amount: Some(amount.into()),
+        };
+        let balance = client
+            .clone()
+            .sandbox_pay_in(request)
+            .await?
+            .into_inner()
+            .balance;
+        Ok(balance.map(|x| x.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TinkoffInvest, TinkoffInvestInterceptor, enums, types};
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    #[test]
+    fn test_client_is_clone_send_sync() {
+        fn assert_clone_send_sync<T: Clone + Send + Sync>() {}
+        assert_clone_send_sync::<TinkoffInvest<TinkoffInvestInterceptor>>();
+    }
+
+    #[allow(dead_code)]
+    fn assert_futures_are_send(tinkoff: &TinkoffInvest<TinkoffInvestInterceptor>) {
+        let uid = types::Uid::from("e6123145-9665-43e0-8413-cd61b8aa9b13");
+        let now = chrono::Utc::now().naive_utc();
+        assert_send(&tinkoff.candlesticks(
+            uid.clone(),
+            enums::CandlestickInterval::Min,
+            now.into(),
+            now.into(),
+        ));
+        assert_send(&tinkoff.orderbook(uid, 10));
+        assert_send(&tinkoff.portfolio(enums::Currency::RUB));
+        assert_send(&tinkoff.broker_report(now.into(), now.into()));
     }
 }
@@ -0,0 +1,36 @@
+use tinkoff_invest_types as tit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetType {
+    Unspecified,
+    Currency,
+    /// Товар.
+    Commodity,
+    Index,
+    /// Ценная бумага.
+    Security,
+}
+
+impl From<tit::AssetType> for AssetType {
+    fn from(value: tit::AssetType) -> Self {
+        match value {
+            tit::AssetType::Unspecified => AssetType::Unspecified,
+            tit::AssetType::Currency => AssetType::Currency,
+            tit::AssetType::Commodity => AssetType::Commodity,
+            tit::AssetType::Index => AssetType::Index,
+            tit::AssetType::Security => AssetType::Security,
+        }
+    }
+}
+
+impl From<AssetType> for tit::AssetType {
+    fn from(value: AssetType) -> Self {
+        match value {
+            AssetType::Unspecified => tit::AssetType::Unspecified,
+            AssetType::Currency => tit::AssetType::Currency,
+            AssetType::Commodity => tit::AssetType::Commodity,
+            AssetType::Index => tit::AssetType::Index,
+            AssetType::Security => tit::AssetType::Security,
+        }
+    }
+}
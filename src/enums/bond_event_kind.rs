@@ -0,0 +1,39 @@
+use tinkoff_invest_types::get_bond_events_request::EventType;
+
+/// Тип события по облигации.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BondEventKind {
+    Unspecified,
+    /// Выплата купона.
+    Coupon,
+    /// Оферта.
+    Call,
+    /// Погашение.
+    Maturity,
+    /// Конвертация.
+    Conversion,
+}
+
+impl From<EventType> for BondEventKind {
+    fn from(value: EventType) -> Self {
+        match value {
+            EventType::Unspecified => BondEventKind::Unspecified,
+            EventType::Cpn => BondEventKind::Coupon,
+            EventType::Call => BondEventKind::Call,
+            EventType::Mty => BondEventKind::Maturity,
+            EventType::Conv => BondEventKind::Conversion,
+        }
+    }
+}
+
+impl From<BondEventKind> for EventType {
+    fn from(value: BondEventKind) -> Self {
+        match value {
+            BondEventKind::Unspecified => EventType::Unspecified,
+            BondEventKind::Coupon => EventType::Cpn,
+            BondEventKind::Call => EventType::Call,
+            BondEventKind::Maturity => EventType::Mty,
+            BondEventKind::Conversion => EventType::Conv,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use tinkoff_invest_types as tit;
 
+use crate::types;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CandlestickInterval {
     Unspecified,
@@ -111,3 +113,84 @@ impl From<tit::SubscriptionInterval> for CandlestickInterval {
         }
     }
 }
+
+impl CandlestickInterval {
+    /// Максимальный период одного запроса `GetCandles` для интервала.
+    pub fn max_request_period(&self) -> Option<chrono::TimeDelta> {
+        match self {
+            CandlestickInterval::Unspecified => None,
+            CandlestickInterval::Second5 => Some(chrono::TimeDelta::minutes(200)),
+            CandlestickInterval::Second10 => Some(chrono::TimeDelta::minutes(200)),
+            CandlestickInterval::Second30 => Some(chrono::TimeDelta::hours(20)),
+            CandlestickInterval::Min => Some(chrono::TimeDelta::days(1)),
+            CandlestickInterval::Min2 => Some(chrono::TimeDelta::days(1)),
+            CandlestickInterval::Min3 => Some(chrono::TimeDelta::days(1)),
+            CandlestickInterval::Min5 => Some(chrono::TimeDelta::days(1)),
+            CandlestickInterval::Min10 => Some(chrono::TimeDelta::days(1)),
+            CandlestickInterval::Min15 => Some(chrono::TimeDelta::days(1)),
+            CandlestickInterval::Min30 => Some(chrono::TimeDelta::days(2)),
+            CandlestickInterval::Hour => Some(chrono::TimeDelta::weeks(1)),
+            CandlestickInterval::Hour2 => Some(chrono::TimeDelta::days(30)),
+            CandlestickInterval::Hour4 => Some(chrono::TimeDelta::days(30)),
+            CandlestickInterval::Day => Some(chrono::TimeDelta::days(365)),
+            CandlestickInterval::Week => Some(chrono::TimeDelta::days(2 * 365)),
+            CandlestickInterval::Month => Some(chrono::TimeDelta::days(10 * 365)),
+        }
+    }
+
+    /// Разбивает период `[from, to)` на периоды, допустимые для одного запроса `GetCandles`.
+    pub fn request_periods(
+        &self,
+        from: &types::DateTime,
+        to: &types::DateTime,
+    ) -> Vec<(types::DateTime, types::DateTime)> {
+        let mut periods = Vec::new();
+        let Some(max_request_period) = self.max_request_period() else {
+            if from < to {
+                periods.push((from.clone(), to.clone()));
+            }
+            return periods;
+        };
+        let to = chrono::NaiveDateTime::from(to.clone());
+        let mut period_from = chrono::NaiveDateTime::from(from.clone());
+        while period_from < to {
+            let period_to = (period_from + max_request_period).min(to);
+            periods.push((period_from.into(), period_to.into()));
+            period_from = period_to;
+        }
+        periods
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::enums::CandlestickInterval;
+    use crate::types::DateTime;
+
+    #[test]
+    fn test_request_periods() {
+        let from = DateTime {
+            seconds: 1_700_000_000,
+            nanoseconds: 0,
+        };
+        let to = DateTime {
+            seconds: 1_700_000_000 + 2 * 86_400 + 3_600,
+            nanoseconds: 0,
+        };
+        let periods = CandlestickInterval::Min.request_periods(&from, &to);
+        assert_eq!(periods.len(), 3);
+        assert_eq!(periods[0].0, from);
+        assert_eq!(periods[0].1.seconds, 1_700_000_000 + 86_400);
+        assert_eq!(periods[1].0, periods[0].1);
+        assert_eq!(periods[2].1, to);
+
+        let periods = CandlestickInterval::Month.request_periods(&from, &to);
+        assert_eq!(periods, vec![(from.clone(), to.clone())]);
+
+        assert!(
+            CandlestickInterval::Min
+                .request_periods(&to, &from)
+                .is_empty()
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::enums;
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ClassCode {
     CETS,
@@ -24,3 +26,25 @@ impl From<ClassCode> for String {
         }
     }
 }
+
+impl ClassCode {
+    /// Торговая площадка, на которой торгуются инструменты с этим класс-кодом.
+    pub fn exchange(&self) -> Option<enums::Exchange> {
+        match self {
+            ClassCode::CETS => Some(enums::Exchange::FX),
+            ClassCode::TQBR => Some(enums::Exchange::MOEX),
+            ClassCode::ClassCode(class_code) => {
+                let class_code = class_code.to_uppercase();
+                if class_code == "SPBFUT" {
+                    Some(enums::Exchange::Exchange("FORTS".into()))
+                } else if class_code.starts_with("TQ") {
+                    Some(enums::Exchange::MOEX)
+                } else if class_code.starts_with("SPB") {
+                    Some(enums::Exchange::SPB)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use tinkoff_invest_types as tit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CouponType {
+    Unspecified,
+    /// Постоянный.
+    Constant,
+    /// Плавающий.
+    Floating,
+    /// Дисконт.
+    Discount,
+    /// Ипотечный.
+    Mortgage,
+    /// Фиксированный.
+    Fix,
+    /// Переменный.
+    Variable,
+    Other,
+}
+
+impl From<tit::CouponType> for CouponType {
+    fn from(value: tit::CouponType) -> Self {
+        match value {
+            tit::CouponType::Unspecified => CouponType::Unspecified,
+            tit::CouponType::Constant => CouponType::Constant,
+            tit::CouponType::Floating => CouponType::Floating,
+            tit::CouponType::Discount => CouponType::Discount,
+            tit::CouponType::Mortgage => CouponType::Mortgage,
+            tit::CouponType::Fix => CouponType::Fix,
+            tit::CouponType::Variable => CouponType::Variable,
+            tit::CouponType::Other => CouponType::Other,
+        }
+    }
+}
+
+impl From<CouponType> for tit::CouponType {
+    fn from(value: CouponType) -> Self {
+        match value {
+            CouponType::Unspecified => tit::CouponType::Unspecified,
+            CouponType::Constant => tit::CouponType::Constant,
+            CouponType::Floating => tit::CouponType::Floating,
+            CouponType::Discount => tit::CouponType::Discount,
+            CouponType::Mortgage => tit::CouponType::Mortgage,
+            CouponType::Fix => tit::CouponType::Fix,
+            CouponType::Variable => tit::CouponType::Variable,
+            CouponType::Other => tit::CouponType::Other,
+        }
+    }
+}
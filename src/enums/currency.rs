@@ -34,3 +34,23 @@ impl From<String> for Currency {
         }
     }
 }
+
+impl From<Currency> for String {
+    fn from(value: Currency) -> Self {
+        match value {
+            Currency::USD => "usd".into(),
+            Currency::RUB => "rub".into(),
+            Currency::EUR => "eur".into(),
+            Currency::CHF => "chf".into(),
+            Currency::CNY => "cny".into(),
+            Currency::GBP => "gbp".into(),
+            Currency::JPY => "jpy".into(),
+            Currency::HKD => "hkd".into(),
+            Currency::SEK => "sek".into(),
+            Currency::NOK => "nok".into(),
+            Currency::CAD => "cad".into(),
+            Currency::KZT => "kzt".into(),
+            Currency::Currency(currency) => currency,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Exchange {
     SPB,
     MOEX,
@@ -30,3 +30,19 @@ impl From<String> for Exchange {
         }
     }
 }
+
+impl From<Exchange> for String {
+    fn from(value: Exchange) -> Self {
+        match value {
+            Exchange::SPB => "SPB".into(),
+            Exchange::MOEX => "MOEX".into(),
+            Exchange::NYSE => "NYSE".into(),
+            Exchange::NASDAQ => "NASDAQ".into(),
+            Exchange::OTCUS => "OTCUS".into(),
+            Exchange::LSE => "LSE".into(),
+            Exchange::XETRA => "XETRA".into(),
+            Exchange::FX => "FX".into(),
+            Exchange::Exchange(exchange) => exchange,
+        }
+    }
+}
@@ -1,9 +1,13 @@
+use tinkoff_invest_types as tit;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InstrumentType {
     Currency,
     Share,
     Future,
-    // Option,
+    Bond,
+    Etf,
+    Option,
 }
 
 impl From<String> for InstrumentType {
@@ -12,7 +16,39 @@ impl From<String> for InstrumentType {
             "currency" => InstrumentType::Currency,
             "share" => InstrumentType::Share,
             "future" => InstrumentType::Future,
+            "bond" => InstrumentType::Bond,
+            "etf" => InstrumentType::Etf,
+            "option" => InstrumentType::Option,
             _ => panic!("{value:?}"),
         }
     }
 }
+
+impl TryFrom<tit::InstrumentType> for InstrumentType {
+    type Error = tit::InstrumentType;
+
+    fn try_from(value: tit::InstrumentType) -> Result<Self, Self::Error> {
+        match value {
+            tit::InstrumentType::Currency => Ok(InstrumentType::Currency),
+            tit::InstrumentType::Share => Ok(InstrumentType::Share),
+            tit::InstrumentType::Futures => Ok(InstrumentType::Future),
+            tit::InstrumentType::Bond => Ok(InstrumentType::Bond),
+            tit::InstrumentType::Etf => Ok(InstrumentType::Etf),
+            tit::InstrumentType::Option => Ok(InstrumentType::Option),
+            _ => Err(value),
+        }
+    }
+}
+
+impl From<InstrumentType> for tit::InstrumentType {
+    fn from(value: InstrumentType) -> Self {
+        match value {
+            InstrumentType::Currency => tit::InstrumentType::Currency,
+            InstrumentType::Share => tit::InstrumentType::Share,
+            InstrumentType::Future => tit::InstrumentType::Futures,
+            InstrumentType::Bond => tit::InstrumentType::Bond,
+            InstrumentType::Etf => tit::InstrumentType::Etf,
+            InstrumentType::Option => tit::InstrumentType::Option,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use tinkoff_invest_types as tit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LastPriceType {
+    Unspecified,
+    /// Цена биржи.
+    Exchange,
+    /// Цена дилера.
+    Dealer,
+}
+
+impl From<tit::LastPriceType> for LastPriceType {
+    fn from(value: tit::LastPriceType) -> Self {
+        match value {
+            tit::LastPriceType::LastPriceUnspecified => LastPriceType::Unspecified,
+            tit::LastPriceType::LastPriceExchange => LastPriceType::Exchange,
+            tit::LastPriceType::LastPriceDealer => LastPriceType::Dealer,
+        }
+    }
+}
+
+impl From<LastPriceType> for tit::LastPriceType {
+    fn from(value: LastPriceType) -> Self {
+        match value {
+            LastPriceType::Unspecified => tit::LastPriceType::LastPriceUnspecified,
+            LastPriceType::Exchange => tit::LastPriceType::LastPriceExchange,
+            LastPriceType::Dealer => tit::LastPriceType::LastPriceDealer,
+        }
+    }
+}
@@ -1,31 +1,65 @@
 mod account_access_level;
 mod account_status;
 mod account_type;
+mod asset_type;
+mod bond_event_kind;
 mod candlestick_interval;
 mod class_code;
+mod coupon_type;
 mod currency;
 mod exchange;
 mod instrument_type;
+mod last_price_type;
 mod market_data_stream_data;
 mod operation_state;
 mod operation_type;
+mod option_direction;
+mod option_settlement_type;
+mod option_style;
 mod order_direction;
+mod order_id_type;
 mod order_kind;
 mod order_status;
+mod price_type;
+mod recommendation;
+mod service;
+mod stop_order_expiration_type;
+mod stop_order_kind;
+mod stop_order_status;
+mod time_in_force;
+mod trade_source;
+mod trading_session_kind;
 mod trading_status;
 
 pub use account_access_level::AccountAccessLevel;
 pub use account_status::AccountStatus;
 pub use account_type::AccountType;
+pub use asset_type::AssetType;
+pub use bond_event_kind::BondEventKind;
 pub use candlestick_interval::CandlestickInterval;
 pub use class_code::ClassCode;
+pub use coupon_type::CouponType;
 pub use currency::Currency;
 pub use exchange::Exchange;
 pub use instrument_type::InstrumentType;
+pub use last_price_type::LastPriceType;
 pub use market_data_stream_data::MarketDataStreamData;
 pub use operation_state::OperationState;
 pub use operation_type::OperationType;
+pub use option_direction::OptionDirection;
+pub use option_settlement_type::OptionSettlementType;
+pub use option_style::OptionStyle;
 pub use order_direction::OrderDirection;
+pub use order_id_type::OrderIdType;
 pub use order_kind::OrderKind;
 pub use order_status::OrderStatus;
+pub use price_type::PriceType;
+pub use recommendation::Recommendation;
+pub use service::Service;
+pub use stop_order_expiration_type::StopOrderExpirationType;
+pub use stop_order_kind::StopOrderKind;
+pub use stop_order_status::StopOrderStatus;
+pub use time_in_force::TimeInForce;
+pub use trade_source::TradeSource;
+pub use trading_session_kind::TradingSessionKind;
 pub use trading_status::TradingStatus;
@@ -137,3 +137,74 @@ impl From<tit::OperationType> for OperationType {
         }
     }
 }
+
+impl From<OperationType> for tit::OperationType {
+    fn from(value: OperationType) -> Self {
+        match value {
+            OperationType::OperationType(_) => tit::OperationType::Unspecified,
+            OperationType::Unspecified => tit::OperationType::Unspecified,
+            OperationType::Input => tit::OperationType::Input,
+            OperationType::BondTax => tit::OperationType::BondTax,
+            OperationType::OutputSecurities => tit::OperationType::OutputSecurities,
+            OperationType::Overnight => tit::OperationType::Overnight,
+            OperationType::Tax => tit::OperationType::Tax,
+            OperationType::BondRepaymentFull => tit::OperationType::BondRepaymentFull,
+            OperationType::SellCard => tit::OperationType::SellCard,
+            OperationType::DividendTax => tit::OperationType::DividendTax,
+            OperationType::Output => tit::OperationType::Output,
+            OperationType::BondRepayment => tit::OperationType::BondRepayment,
+            OperationType::TaxCorrection => tit::OperationType::TaxCorrection,
+            OperationType::ServiceFee => tit::OperationType::ServiceFee,
+            OperationType::BenefitTax => tit::OperationType::BenefitTax,
+            OperationType::MarginFee => tit::OperationType::MarginFee,
+            OperationType::Buy => tit::OperationType::Buy,
+            OperationType::BuyCard => tit::OperationType::BuyCard,
+            OperationType::InputSecurities => tit::OperationType::InputSecurities,
+            OperationType::SellMargin => tit::OperationType::SellMargin,
+            OperationType::BrokerFee => tit::OperationType::BrokerFee,
+            OperationType::BuyMargin => tit::OperationType::BuyMargin,
+            OperationType::Dividend => tit::OperationType::Dividend,
+            OperationType::Sell => tit::OperationType::Sell,
+            OperationType::Coupon => tit::OperationType::Coupon,
+            OperationType::SuccessFee => tit::OperationType::SuccessFee,
+            OperationType::DividendTransfer => tit::OperationType::DividendTransfer,
+            OperationType::AccruingVarmargin => tit::OperationType::AccruingVarmargin,
+            OperationType::WritingOffVarmargin => tit::OperationType::WritingOffVarmargin,
+            OperationType::DeliveryBuy => tit::OperationType::DeliveryBuy,
+            OperationType::DeliverySell => tit::OperationType::DeliverySell,
+            OperationType::TrackMfee => tit::OperationType::TrackMfee,
+            OperationType::TrackPfee => tit::OperationType::TrackPfee,
+            OperationType::TaxProgressive => tit::OperationType::TaxProgressive,
+            OperationType::BondTaxProgressive => tit::OperationType::BondTaxProgressive,
+            OperationType::DividendTaxProgressive => tit::OperationType::DividendTaxProgressive,
+            OperationType::BenefitTaxProgressive => tit::OperationType::BenefitTaxProgressive,
+            OperationType::TaxCorrectionProgressive => tit::OperationType::TaxCorrectionProgressive,
+            OperationType::TaxRepoProgressive => tit::OperationType::TaxRepoProgressive,
+            OperationType::TaxRepo => tit::OperationType::TaxRepo,
+            OperationType::TaxRepoHold => tit::OperationType::TaxRepoHold,
+            OperationType::TaxRepoRefund => tit::OperationType::TaxRepoRefund,
+            OperationType::TaxRepoHoldProgressive => tit::OperationType::TaxRepoHoldProgressive,
+            OperationType::TaxRepoRefundProgressive => tit::OperationType::TaxRepoRefundProgressive,
+            OperationType::DivExt => tit::OperationType::DivExt,
+            OperationType::TaxCorrectionCoupon => tit::OperationType::TaxCorrectionCoupon,
+            OperationType::CashFee => tit::OperationType::CashFee,
+            OperationType::OutFee => tit::OperationType::OutFee,
+            OperationType::OutStampDuty => tit::OperationType::OutStampDuty,
+            OperationType::OutputSwift => tit::OperationType::OutputSwift,
+            OperationType::InputSwift => tit::OperationType::InputSwift,
+            OperationType::OutputAcquiring => tit::OperationType::OutputAcquiring,
+            OperationType::InputAcquiring => tit::OperationType::InputAcquiring,
+            OperationType::OutputPenalty => tit::OperationType::OutputPenalty,
+            OperationType::AdviceFee => tit::OperationType::AdviceFee,
+            OperationType::TransIisBs => tit::OperationType::TransIisBs,
+            OperationType::TransBsBs => tit::OperationType::TransBsBs,
+            OperationType::OutMulti => tit::OperationType::OutMulti,
+            OperationType::InpMulti => tit::OperationType::InpMulti,
+            OperationType::OverPlacement => tit::OperationType::OverPlacement,
+            OperationType::OverCom => tit::OperationType::OverCom,
+            OperationType::OverIncome => tit::OperationType::OverIncome,
+            OperationType::OptionExpiration => tit::OperationType::OptionExpiration,
+            OperationType::FutureExpiration => tit::OperationType::FutureExpiration,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use tinkoff_invest_types as tit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OptionDirection {
+    Unspecified,
+    Put,
+    Call,
+}
+
+impl From<tit::OptionDirection> for OptionDirection {
+    fn from(value: tit::OptionDirection) -> Self {
+        match value {
+            tit::OptionDirection::Unspecified => OptionDirection::Unspecified,
+            tit::OptionDirection::Put => OptionDirection::Put,
+            tit::OptionDirection::Call => OptionDirection::Call,
+        }
+    }
+}
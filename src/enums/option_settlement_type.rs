@@ -0,0 +1,24 @@
+use tinkoff_invest_types as tit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OptionSettlementType {
+    Unspecified,
+    PhysicalDelivery,
+    CashSettlement,
+}
+
+impl From<tit::OptionSettlementType> for OptionSettlementType {
+    fn from(value: tit::OptionSettlementType) -> Self {
+        match value {
+            tit::OptionSettlementType::OptionExecutionTypeUnspecified => {
+                OptionSettlementType::Unspecified
+            }
+            tit::OptionSettlementType::OptionExecutionTypePhysicalDelivery => {
+                OptionSettlementType::PhysicalDelivery
+            }
+            tit::OptionSettlementType::OptionExecutionTypeCashSettlement => {
+                OptionSettlementType::CashSettlement
+            }
+        }
+    }
+}
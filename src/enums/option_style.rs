@@ -0,0 +1,18 @@
+use tinkoff_invest_types as tit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OptionStyle {
+    Unspecified,
+    American,
+    European,
+}
+
+impl From<tit::OptionStyle> for OptionStyle {
+    fn from(value: tit::OptionStyle) -> Self {
+        match value {
+            tit::OptionStyle::Unspecified => OptionStyle::Unspecified,
+            tit::OptionStyle::American => OptionStyle::American,
+            tit::OptionStyle::European => OptionStyle::European,
+        }
+    }
+}
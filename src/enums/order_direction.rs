@@ -26,3 +26,33 @@ impl From<OrderDirection> for tit::OrderDirection {
         }
     }
 }
+
+impl From<tit::StopOrderDirection> for OrderDirection {
+    fn from(value: tit::StopOrderDirection) -> Self {
+        match value {
+            tit::StopOrderDirection::Unspecified => OrderDirection::Unspecified,
+            tit::StopOrderDirection::Buy => OrderDirection::Buy,
+            tit::StopOrderDirection::Sell => OrderDirection::Sell,
+        }
+    }
+}
+
+impl From<OrderDirection> for tit::StopOrderDirection {
+    fn from(value: OrderDirection) -> Self {
+        match value {
+            OrderDirection::Unspecified => tit::StopOrderDirection::Unspecified,
+            OrderDirection::Buy => tit::StopOrderDirection::Buy,
+            OrderDirection::Sell => tit::StopOrderDirection::Sell,
+        }
+    }
+}
+
+impl From<tit::TradeDirection> for OrderDirection {
+    fn from(value: tit::TradeDirection) -> Self {
+        match value {
+            tit::TradeDirection::Unspecified => OrderDirection::Unspecified,
+            tit::TradeDirection::Buy => OrderDirection::Buy,
+            tit::TradeDirection::Sell => OrderDirection::Sell,
+        }
+    }
+}
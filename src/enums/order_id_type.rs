@@ -0,0 +1,28 @@
+use tinkoff_invest_types as tit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderIdType {
+    Unspecified,
+    Exchange,
+    Request,
+}
+
+impl From<tit::OrderIdType> for OrderIdType {
+    fn from(value: tit::OrderIdType) -> Self {
+        match value {
+            tit::OrderIdType::Unspecified => OrderIdType::Unspecified,
+            tit::OrderIdType::Exchange => OrderIdType::Exchange,
+            tit::OrderIdType::Request => OrderIdType::Request,
+        }
+    }
+}
+
+impl From<OrderIdType> for tit::OrderIdType {
+    fn from(value: OrderIdType) -> Self {
+        match value {
+            OrderIdType::Unspecified => tit::OrderIdType::Unspecified,
+            OrderIdType::Exchange => tit::OrderIdType::Exchange,
+            OrderIdType::Request => tit::OrderIdType::Request,
+        }
+    }
+}
@@ -18,3 +18,14 @@ impl From<tit::OrderType> for OrderKind {
         }
     }
 }
+
+impl From<OrderKind> for tit::OrderType {
+    fn from(value: OrderKind) -> Self {
+        match value {
+            OrderKind::Unspecified => tit::OrderType::Unspecified,
+            OrderKind::Limit => tit::OrderType::Limit,
+            OrderKind::Market => tit::OrderType::Market,
+            OrderKind::BestPrice => tit::OrderType::Bestprice,
+        }
+    }
+}
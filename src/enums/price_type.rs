@@ -0,0 +1,28 @@
+use tinkoff_invest_types as tit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PriceType {
+    Unspecified,
+    Point,
+    Currency,
+}
+
+impl From<tit::PriceType> for PriceType {
+    fn from(value: tit::PriceType) -> Self {
+        match value {
+            tit::PriceType::Unspecified => PriceType::Unspecified,
+            tit::PriceType::Point => PriceType::Point,
+            tit::PriceType::Currency => PriceType::Currency,
+        }
+    }
+}
+
+impl From<PriceType> for tit::PriceType {
+    fn from(value: PriceType) -> Self {
+        match value {
+            PriceType::Unspecified => tit::PriceType::Unspecified,
+            PriceType::Point => tit::PriceType::Point,
+            PriceType::Currency => tit::PriceType::Currency,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use tinkoff_invest_types as tit;
+
+/// Рекомендация аналитиков.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Recommendation {
+    Unspecified,
+    Buy,
+    Hold,
+    Sell,
+}
+
+impl From<tit::Recommendation> for Recommendation {
+    fn from(value: tit::Recommendation) -> Self {
+        match value {
+            tit::Recommendation::Unspecified => Recommendation::Unspecified,
+            tit::Recommendation::Buy => Recommendation::Buy,
+            tit::Recommendation::Hold => Recommendation::Hold,
+            tit::Recommendation::Sell => Recommendation::Sell,
+        }
+    }
+}
+
+impl From<Recommendation> for tit::Recommendation {
+    fn from(value: Recommendation) -> Self {
+        match value {
+            Recommendation::Unspecified => tit::Recommendation::Unspecified,
+            Recommendation::Buy => tit::Recommendation::Buy,
+            Recommendation::Hold => tit::Recommendation::Hold,
+            Recommendation::Sell => tit::Recommendation::Sell,
+        }
+    }
+}
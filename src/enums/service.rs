@@ -0,0 +1,35 @@
+/// Сервис API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Service {
+    Users,
+    Instruments,
+    MarketData,
+    MarketDataStream,
+    Operations,
+    OperationsStream,
+    Orders,
+    OrdersStream,
+    Sandbox,
+    StopOrders,
+}
+
+impl Service {
+    /// Сервис по имени метода, например
+    /// `tinkoff.public.invest.api.contract.v1.MarketDataService/GetCandles`.
+    pub fn from_method(method: &str) -> Option<Self> {
+        let service = method.trim_start_matches('/').split('/').next()?;
+        match service.rsplit('.').next()? {
+            "UsersService" => Some(Service::Users),
+            "InstrumentsService" => Some(Service::Instruments),
+            "MarketDataService" => Some(Service::MarketData),
+            "MarketDataStreamService" => Some(Service::MarketDataStream),
+            "OperationsService" => Some(Service::Operations),
+            "OperationsStreamService" => Some(Service::OperationsStream),
+            "OrdersService" => Some(Service::Orders),
+            "OrdersStreamService" => Some(Service::OrdersStream),
+            "SandboxService" => Some(Service::Sandbox),
+            "StopOrdersService" => Some(Service::StopOrders),
+            _ => None,
+        }
+    }
+}
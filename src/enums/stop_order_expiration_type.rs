@@ -0,0 +1,28 @@
+use tinkoff_invest_types as tit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StopOrderExpirationType {
+    Unspecified,
+    GoodTillCancel,
+    GoodTillDate,
+}
+
+impl From<tit::StopOrderExpirationType> for StopOrderExpirationType {
+    fn from(value: tit::StopOrderExpirationType) -> Self {
+        match value {
+            tit::StopOrderExpirationType::Unspecified => StopOrderExpirationType::Unspecified,
+            tit::StopOrderExpirationType::GoodTillCancel => StopOrderExpirationType::GoodTillCancel,
+            tit::StopOrderExpirationType::GoodTillDate => StopOrderExpirationType::GoodTillDate,
+        }
+    }
+}
+
+impl From<StopOrderExpirationType> for tit::StopOrderExpirationType {
+    fn from(value: StopOrderExpirationType) -> Self {
+        match value {
+            StopOrderExpirationType::Unspecified => tit::StopOrderExpirationType::Unspecified,
+            StopOrderExpirationType::GoodTillCancel => tit::StopOrderExpirationType::GoodTillCancel,
+            StopOrderExpirationType::GoodTillDate => tit::StopOrderExpirationType::GoodTillDate,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use tinkoff_invest_types as tit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StopOrderKind {
+    Unspecified,
+    TakeProfit,
+    StopLoss,
+    StopLimit,
+}
+
+impl From<tit::StopOrderType> for StopOrderKind {
+    fn from(value: tit::StopOrderType) -> Self {
+        match value {
+            tit::StopOrderType::Unspecified => StopOrderKind::Unspecified,
+            tit::StopOrderType::TakeProfit => StopOrderKind::TakeProfit,
+            tit::StopOrderType::StopLoss => StopOrderKind::StopLoss,
+            tit::StopOrderType::StopLimit => StopOrderKind::StopLimit,
+        }
+    }
+}
+
+impl From<StopOrderKind> for tit::StopOrderType {
+    fn from(value: StopOrderKind) -> Self {
+        match value {
+            StopOrderKind::Unspecified => tit::StopOrderType::Unspecified,
+            StopOrderKind::TakeProfit => tit::StopOrderType::TakeProfit,
+            StopOrderKind::StopLoss => tit::StopOrderType::StopLoss,
+            StopOrderKind::StopLimit => tit::StopOrderType::StopLimit,
+        }
+    }
+}
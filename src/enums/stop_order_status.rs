@@ -0,0 +1,37 @@
+use tinkoff_invest_types as tit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StopOrderStatus {
+    Unspecified,
+    All,
+    Active,
+    Executed,
+    Canceled,
+    Expired,
+}
+
+impl From<tit::StopOrderStatusOption> for StopOrderStatus {
+    fn from(value: tit::StopOrderStatusOption) -> Self {
+        match value {
+            tit::StopOrderStatusOption::StopOrderStatusUnspecified => StopOrderStatus::Unspecified,
+            tit::StopOrderStatusOption::StopOrderStatusAll => StopOrderStatus::All,
+            tit::StopOrderStatusOption::StopOrderStatusActive => StopOrderStatus::Active,
+            tit::StopOrderStatusOption::StopOrderStatusExecuted => StopOrderStatus::Executed,
+            tit::StopOrderStatusOption::StopOrderStatusCanceled => StopOrderStatus::Canceled,
+            tit::StopOrderStatusOption::StopOrderStatusExpired => StopOrderStatus::Expired,
+        }
+    }
+}
+
+impl From<StopOrderStatus> for tit::StopOrderStatusOption {
+    fn from(value: StopOrderStatus) -> Self {
+        match value {
+            StopOrderStatus::Unspecified => tit::StopOrderStatusOption::StopOrderStatusUnspecified,
+            StopOrderStatus::All => tit::StopOrderStatusOption::StopOrderStatusAll,
+            StopOrderStatus::Active => tit::StopOrderStatusOption::StopOrderStatusActive,
+            StopOrderStatus::Executed => tit::StopOrderStatusOption::StopOrderStatusExecuted,
+            StopOrderStatus::Canceled => tit::StopOrderStatusOption::StopOrderStatusCanceled,
+            StopOrderStatus::Expired => tit::StopOrderStatusOption::StopOrderStatusExpired,
+        }
+    }
+}
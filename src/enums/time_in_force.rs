@@ -0,0 +1,31 @@
+use tinkoff_invest_types as tit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeInForce {
+    Unspecified,
+    Day,
+    FillAndKill,
+    FillOrKill,
+}
+
+impl From<tit::TimeInForceType> for TimeInForce {
+    fn from(value: tit::TimeInForceType) -> Self {
+        match value {
+            tit::TimeInForceType::TimeInForceUnspecified => TimeInForce::Unspecified,
+            tit::TimeInForceType::TimeInForceDay => TimeInForce::Day,
+            tit::TimeInForceType::TimeInForceFillAndKill => TimeInForce::FillAndKill,
+            tit::TimeInForceType::TimeInForceFillOrKill => TimeInForce::FillOrKill,
+        }
+    }
+}
+
+impl From<TimeInForce> for tit::TimeInForceType {
+    fn from(value: TimeInForce) -> Self {
+        match value {
+            TimeInForce::Unspecified => tit::TimeInForceType::TimeInForceUnspecified,
+            TimeInForce::Day => tit::TimeInForceType::TimeInForceDay,
+            TimeInForce::FillAndKill => tit::TimeInForceType::TimeInForceFillAndKill,
+            TimeInForce::FillOrKill => tit::TimeInForceType::TimeInForceFillOrKill,
+        }
+    }
+}
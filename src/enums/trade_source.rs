@@ -0,0 +1,34 @@
+use tinkoff_invest_types as tit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TradeSource {
+    Unspecified,
+    /// Биржевые сделки.
+    Exchange,
+    /// Сделки дилера.
+    Dealer,
+    /// Все сделки.
+    All,
+}
+
+impl From<tit::TradeSourceType> for TradeSource {
+    fn from(value: tit::TradeSourceType) -> Self {
+        match value {
+            tit::TradeSourceType::TradeSourceUnspecified => TradeSource::Unspecified,
+            tit::TradeSourceType::TradeSourceExchange => TradeSource::Exchange,
+            tit::TradeSourceType::TradeSourceDealer => TradeSource::Dealer,
+            tit::TradeSourceType::TradeSourceAll => TradeSource::All,
+        }
+    }
+}
+
+impl From<TradeSource> for tit::TradeSourceType {
+    fn from(value: TradeSource) -> Self {
+        match value {
+            TradeSource::Unspecified => tit::TradeSourceType::TradeSourceUnspecified,
+            TradeSource::Exchange => tit::TradeSourceType::TradeSourceExchange,
+            TradeSource::Dealer => tit::TradeSourceType::TradeSourceDealer,
+            TradeSource::All => tit::TradeSourceType::TradeSourceAll,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+/// Торговая сессия или перерыв в течение торгового дня.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TradingSessionKind {
+    Premarket,
+    OpeningAuction,
+    Main,
+    ClosingAuction,
+    /// Клиринг, торги приостановлены.
+    Clearing,
+    EveningOpeningAuction,
+    Evening,
+}
+
+impl TradingSessionKind {
+    /// Идут ли торги в течение сессии.
+    #[inline]
+    pub fn is_trading(&self) -> bool {
+        !matches!(self, TradingSessionKind::Clearing)
+    }
+}
@@ -1,6 +1,7 @@
-use std::error::Error;
 use std::fmt::{Display, Formatter, Result};
 
+use tonic::{Code, Status};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TinkoffInvestError {
     InterceptorNotSet,
@@ -11,13 +12,19 @@ pub enum TinkoffInvestError {
     InstrumentsServiceClientNotInit,
     MarketDataServiceClientNotInit,
     MarketDataStreamServiceClientNotInit,
+    MarketDataStreamClosed,
     OperationsServiceClientNotInit,
     OperationsStreamServiceClientNotInit,
     OrdersServiceClientNotInit,
     OrdersStreamServiceClientNotInit,
+    SandboxServiceClientNotInit,
+    StopOrdersServiceClientNotInit,
     MarketInstrumentTypeNotCurrency,
     MarketInstrumentTypeNotShare,
     MarketInstrumentTypeNotFuture,
+    MarketInstrumentTypeNotBond,
+    MarketInstrumentTypeNotEtf,
+    MarketInstrumentTypeNotOption,
     CandlestickFigiNotSet,
     CandlestickIntervalNotSet,
     CandlestickPriceOpenNotSet,
@@ -27,6 +34,11 @@ pub enum TinkoffInvestError {
     CandlestickDatetimeNotSet,
     FigiNotFound,
     FigiNotSet,
+    CurrencyNotSupported,
+    ReportTaskIdNotSet,
+    ReportNotReady,
+    TaskFailed,
+    PriceNotSet,
 }
 
 impl Display for TinkoffInvestError {
@@ -35,9 +47,15 @@ impl Display for TinkoffInvestError {
             TinkoffInvestError::InterceptorNotSet => {
                 write!(f, "Interceptor not set.")
             }
+            TinkoffInvestError::HandlerNotSet => {
+                write!(f, "Handler not set.")
+            }
             TinkoffInvestError::AccountNotSet => {
                 write!(f, "Account not set.")
             }
+            TinkoffInvestError::ChannelNotSet => {
+                write!(f, "Channel not set.")
+            }
             TinkoffInvestError::UsersServiceClientNotInit => {
                 write!(f, "Users service client not init.")
             }
@@ -50,6 +68,9 @@ impl Display for TinkoffInvestError {
             TinkoffInvestError::MarketDataStreamServiceClientNotInit => {
                 write!(f, "Market data stream service client not init.")
             }
+            TinkoffInvestError::MarketDataStreamClosed => {
+                write!(f, "Market data stream closed.")
+            }
             TinkoffInvestError::OperationsServiceClientNotInit => {
                 write!(f, "Operations service client not init.")
             }
@@ -62,11 +83,236 @@ impl Display for TinkoffInvestError {
             TinkoffInvestError::OrdersStreamServiceClientNotInit => {
                 write!(f, "Orders stream service client not init.")
             }
-            _ => {
-                write!(f, "")
+            TinkoffInvestError::SandboxServiceClientNotInit => {
+                write!(f, "Sandbox service client not init.")
+            }
+            TinkoffInvestError::StopOrdersServiceClientNotInit => {
+                write!(f, "Stop orders service client not init.")
+            }
+            TinkoffInvestError::MarketInstrumentTypeNotCurrency => {
+                write!(f, "Market instrument type not currency.")
+            }
+            TinkoffInvestError::MarketInstrumentTypeNotShare => {
+                write!(f, "Market instrument type not share.")
+            }
+            TinkoffInvestError::MarketInstrumentTypeNotFuture => {
+                write!(f, "Market instrument type not future.")
+            }
+            TinkoffInvestError::MarketInstrumentTypeNotBond => {
+                write!(f, "Market instrument type not bond.")
+            }
+            TinkoffInvestError::MarketInstrumentTypeNotEtf => {
+                write!(f, "Market instrument type not etf.")
+            }
+            TinkoffInvestError::MarketInstrumentTypeNotOption => {
+                write!(f, "Market instrument type not option.")
+            }
+            TinkoffInvestError::CandlestickFigiNotSet => {
+                write!(f, "Candlestick figi not set.")
+            }
+            TinkoffInvestError::CandlestickIntervalNotSet => {
+                write!(f, "Candlestick interval not set.")
+            }
+            TinkoffInvestError::CandlestickPriceOpenNotSet => {
+                write!(f, "Candlestick price open not set.")
+            }
+            TinkoffInvestError::CandlestickPriceHighNotSet => {
+                write!(f, "Candlestick price high not set.")
+            }
+            TinkoffInvestError::CandlestickPriceLowNotSet => {
+                write!(f, "Candlestick price low not set.")
+            }
+            TinkoffInvestError::CandlestickPriceCloseNotSet => {
+                write!(f, "Candlestick price close not set.")
+            }
+            TinkoffInvestError::CandlestickDatetimeNotSet => {
+                write!(f, "Candlestick datetime not set.")
+            }
+            TinkoffInvestError::FigiNotFound => {
+                write!(f, "Figi not found.")
+            }
+            TinkoffInvestError::FigiNotSet => {
+                write!(f, "Figi not set.")
+            }
+            TinkoffInvestError::CurrencyNotSupported => {
+                write!(f, "Currency not supported.")
+            }
+            TinkoffInvestError::ReportTaskIdNotSet => {
+                write!(f, "Report task id not set.")
+            }
+            TinkoffInvestError::ReportNotReady => {
+                write!(f, "Report not ready.")
+            }
+            TinkoffInvestError::TaskFailed => {
+                write!(f, "Task failed.")
+            }
+            TinkoffInvestError::PriceNotSet => {
+                write!(f, "Price not set.")
             }
         }
     }
 }
 
-impl Error for TinkoffInvestError {}
+impl std::error::Error for TinkoffInvestError {}
+
+/// Ошибка клиента.
+#[derive(Debug)]
+pub enum Error {
+    /// Ошибка транспортного уровня (подключение, TLS).
+    Transport(tonic::transport::Error),
+    /// Ошибка, которую вернул сервер.
+    Status {
+        status: Status,
+        /// Числовой код ошибки брокера.
+        code: Option<u32>,
+        /// Значение заголовка `x-tracking-id` ответа.
+        tracking_id: Option<String>,
+    },
+    /// Ошибка клиента.
+    TinkoffInvest(TinkoffInvestError),
+}
+
+impl Error {
+    /// gRPC код ответа, если ошибку вернул сервер.
+    #[inline]
+    pub fn grpc_code(&self) -> Option<Code> {
+        match self {
+            Error::Status { status, .. } => Some(status.code()),
+            _ => None,
+        }
+    }
+
+    /// Числовой код ошибки брокера, если ошибку вернул сервер.
+    #[inline]
+    pub fn code(&self) -> Option<u32> {
+        match self {
+            Error::Status { code, .. } => *code,
+            _ => None,
+        }
+    }
+
+    /// Значение заголовка `x-tracking-id` ответа, если ошибку вернул сервер.
+    #[inline]
+    pub fn tracking_id(&self) -> Option<&str> {
+        match self {
+            Error::Status { tracking_id, .. } => tracking_id.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Error::Transport(error) => {
+                write!(f, "Transport error: {error}")
+            }
+            Error::Status {
+                status,
+                code,
+                tracking_id,
+            } => {
+                write!(f, "Status error: {:?}", status.code())?;
+                if let Some(code) = code {
+                    write!(f, ", code: {code}")?;
+                }
+                let description = status
+                    .metadata()
+                    .get("message")
+                    .and_then(|x| x.to_str().ok())
+                    .unwrap_or_else(|| status.message());
+                if !description.is_empty() {
+                    write!(f, ", message: {description}")?;
+                }
+                if let Some(tracking_id) = tracking_id {
+                    write!(f, ", tracking id: {tracking_id}")?;
+                }
+                Ok(())
+            }
+            Error::TinkoffInvest(error) => {
+                write!(f, "{error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Transport(error) => Some(error),
+            Error::Status { status, .. } => Some(status),
+            Error::TinkoffInvest(error) => Some(error),
+        }
+    }
+}
+
+impl From<tonic::transport::Error> for Error {
+    fn from(value: tonic::transport::Error) -> Self {
+        Error::Transport(value)
+    }
+}
+
+impl From<Status> for Error {
+    fn from(value: Status) -> Self {
+        let code = value
+            .message()
+            .split(|x: char| !x.is_ascii_digit())
+            .next()
+            .and_then(|x| x.parse().ok());
+        let tracking_id = value
+            .metadata()
+            .get("x-tracking-id")
+            .and_then(|x| x.to_str().ok())
+            .map(|x| x.to_string());
+        Error::Status {
+            status: value,
+            code,
+            tracking_id,
+        }
+    }
+}
+
+impl From<TinkoffInvestError> for Error {
+    fn from(value: TinkoffInvestError) -> Self {
+        Error::TinkoffInvest(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tonic::metadata::MetadataMap;
+    use tonic::{Code, Status};
+
+    use crate::{Error, TinkoffInvestError};
+
+    #[test]
+    fn test_status_code_and_tracking_id() {
+        let mut metadata = MetadataMap::new();
+        metadata.insert("x-tracking-id", "ba2ad5f7a8e4e4f6".parse().unwrap());
+        metadata.insert("message", "instrument not found".parse().unwrap());
+        let status = Status::with_metadata(Code::NotFound, "50002", metadata);
+        let error = Error::from(status);
+        assert_eq!(error.grpc_code(), Some(Code::NotFound));
+        assert_eq!(error.code(), Some(50002));
+        assert_eq!(error.tracking_id(), Some("ba2ad5f7a8e4e4f6"));
+        assert_eq!(
+            error.to_string(),
+            "Status error: NotFound, code: 50002, message: instrument not found, tracking id: ba2ad5f7a8e4e4f6"
+        );
+    }
+
+    #[test]
+    fn test_status_without_code() {
+        let error = Error::from(Status::unavailable("connection reset"));
+        assert_eq!(error.code(), None);
+        assert_eq!(error.tracking_id(), None);
+    }
+
+    #[test]
+    fn test_error_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Error>();
+        let error = Error::from(TinkoffInvestError::AccountNotSet);
+        assert_eq!(error.to_string(), "Account not set.");
+    }
+}
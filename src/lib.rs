@@ -5,13 +5,16 @@ mod client;
 pub mod enums;
 mod error;
 mod interceptor;
+mod rate_limiter;
+mod retry;
 pub mod streams;
 pub mod traits;
 pub mod types;
 
 pub use client::{TinkoffInvest, TinkoffInvestBuilder};
-pub use error::TinkoffInvestError;
+pub use error::{Error, TinkoffInvestError};
 pub use interceptor::TinkoffInvestInterceptor;
+pub use retry::RetryPolicy;
 
 // re-export
 pub use chrono;
@@ -0,0 +1,323 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tinkoff_invest_types::GetUserTariffResponse;
+use tonic::body::Body;
+use tonic::codegen::http::{HeaderMap, Request, Response};
+use tonic::codegen::{BoxFuture, Context, Poll, Service};
+use tonic::transport::Channel;
+
+use crate::enums;
+
+const RATE_LIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+const RATE_LIMIT_RESET_HEADER: &str = "x-ratelimit-reset";
+
+#[derive(Debug)]
+struct TokenBucket {
+    limit_per_minute: Option<u32>,
+    tokens: f64,
+    updated_at: Instant,
+    reset_at: Option<Instant>,
+}
+
+impl TokenBucket {
+    fn new(limit_per_minute: Option<u32>, now: Instant) -> Self {
+        Self {
+            limit_per_minute,
+            tokens: limit_per_minute.unwrap_or_default() as f64,
+            updated_at: now,
+            reset_at: None,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        if let Some(limit_per_minute) = self.limit_per_minute {
+            let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+            self.tokens = (self.tokens + elapsed * limit_per_minute as f64 / 60.0)
+                .min(limit_per_minute as f64);
+        }
+        self.updated_at = now;
+    }
+
+    /// Забирает токен. Если токенов нет, возвращает время ожидания.
+    fn try_acquire(&mut self, now: Instant) -> Option<Duration> {
+        if let Some(reset_at) = self.reset_at {
+            if reset_at > now {
+                return Some(reset_at - now);
+            }
+            self.reset_at = None;
+            self.tokens = self.limit_per_minute.unwrap_or_default() as f64;
+            self.updated_at = now;
+        }
+        let limit_per_minute = self.limit_per_minute?;
+        if limit_per_minute == 0 {
+            return None;
+        }
+        self.refill(now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            None
+        } else {
+            let seconds = (1.0 - self.tokens) * 60.0 / limit_per_minute as f64;
+            Some(Duration::from_secs_f64(seconds))
+        }
+    }
+
+    /// Обновляет состояние по заголовкам ответа.
+    fn update(&mut self, remaining: u32, reset: Duration, now: Instant) {
+        self.refill(now);
+        if remaining == 0 {
+            self.tokens = 0.0;
+            self.reset_at = Some(now + reset);
+        } else {
+            self.tokens = self.tokens.min(remaining as f64);
+        }
+    }
+}
+
+#[inline]
+fn header_value<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers
+        .get(name)?
+        .to_str()
+        .ok()?
+        .split(',')
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Ключ корзины токенов.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum BucketKey {
+    /// Лимит сервиса, заданный вручную или полученный из заголовков ответа.
+    Service(enums::Service),
+    /// Группа методов с общим лимитом из тарифа.
+    TariffGroup(usize),
+}
+
+#[derive(Debug, Default)]
+struct RateLimiterState {
+    /// Сервисы с лимитом, заданным вручную.
+    services: HashSet<enums::Service>,
+    /// Группа лимита тарифа для каждого метода.
+    methods: HashMap<String, usize>,
+    buckets: HashMap<BucketKey, TokenBucket>,
+}
+
+impl RateLimiterState {
+    /// Корзина для метода: ручной лимит сервиса, затем группа тарифа, затем сервис.
+    fn key(&self, method: &str) -> Option<BucketKey> {
+        let service = enums::Service::from_method(method)?;
+        if self.services.contains(&service) {
+            return Some(BucketKey::Service(service));
+        }
+        match self.methods.get(method.trim_start_matches('/')) {
+            Some(group) => Some(BucketKey::TariffGroup(*group)),
+            None => Some(BucketKey::Service(service)),
+        }
+    }
+}
+
+/// Ограничитель количества unary-запросов к сервисам API.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RateLimiter {
+    state: Arc<Mutex<RateLimiterState>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(limits: &HashMap<enums::Service, u32>) -> Self {
+        let now = Instant::now();
+        let state = RateLimiterState {
+            services: limits.keys().copied().collect(),
+            methods: HashMap::new(),
+            buckets: limits
+                .iter()
+                .map(|(service, limit)| {
+                    (
+                        BucketKey::Service(*service),
+                        TokenBucket::new(Some(*limit), now),
+                    )
+                })
+                .collect(),
+        };
+        Self {
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    /// Устанавливает лимиты по тарифу пользователя.
+    /// Методы одной группы тарифа расходуют общий лимит, ручные лимиты сервисов имеют приоритет.
+    pub(crate) fn set_tariff(&self, tariff: &GetUserTariffResponse) {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        for (group, unary_limit) in tariff.unary_limits.iter().enumerate() {
+            let limit_per_minute = unary_limit.limit_per_minute.max(0) as u32;
+            for method in &unary_limit.methods {
+                state
+                    .methods
+                    .insert(method.trim_start_matches('/').to_string(), group);
+            }
+            state.buckets.insert(
+                BucketKey::TariffGroup(group),
+                TokenBucket::new(Some(limit_per_minute), now),
+            );
+        }
+    }
+
+    /// Ожидает, пока метод не будет готов принять запрос.
+    pub(crate) async fn acquire(&self, method: &str) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                let key = state.key(method);
+                key.and_then(|key| state.buckets.get_mut(&key))
+                    .and_then(|bucket| bucket.try_acquire(Instant::now()))
+            };
+            match wait {
+                Some(duration) => tokio::time::sleep(duration).await,
+                None => return,
+            }
+        }
+    }
+
+    pub(crate) fn update(&self, method: &str, headers: &HeaderMap) {
+        let Some(remaining) = header_value::<u32>(headers, RATE_LIMIT_REMAINING_HEADER) else {
+            return;
+        };
+        let reset = header_value::<u64>(headers, RATE_LIMIT_RESET_HEADER).unwrap_or_default();
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        let Some(key) = state.key(method) else {
+            return;
+        };
+        state
+            .buckets
+            .entry(key)
+            .or_insert_with(|| TokenBucket::new(None, now))
+            .update(remaining, Duration::from_secs(reset), now);
+    }
+}
+
+/// Канал, ограничивающий количество unary-запросов к сервисам API.
+#[derive(Debug, Clone)]
+pub(crate) struct RateLimitedChannel {
+    channel: Channel,
+    rate_limiter: RateLimiter,
+}
+
+impl RateLimitedChannel {
+    pub(crate) fn new(channel: Channel, rate_limiter: RateLimiter) -> Self {
+        Self {
+            channel,
+            rate_limiter,
+        }
+    }
+}
+
+impl Service<Request<Body>> for RateLimitedChannel {
+    type Response = Response<Body>;
+    type Error = tonic::transport::Error;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.channel.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let clone = self.channel.clone();
+        let mut channel = std::mem::replace(&mut self.channel, clone);
+        let rate_limiter = self.rate_limiter.clone();
+        Box::pin(async move {
+            let method = request.uri().path().to_string();
+            rate_limiter.acquire(&method).await;
+            let response = channel.call(request).await?;
+            rate_limiter.update(&method, response.headers());
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::{Duration, Instant};
+
+    use tinkoff_invest_types::{GetUserTariffResponse, UnaryLimit};
+
+    use super::{BucketKey, RateLimiter, TokenBucket};
+    use crate::enums::Service;
+
+    #[test]
+    fn test_token_bucket() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(Some(60), now);
+        for _ in 0..60 {
+            assert_eq!(bucket.try_acquire(now), None);
+        }
+        assert_eq!(bucket.try_acquire(now), Some(Duration::from_secs(1)));
+        assert_eq!(bucket.try_acquire(now + Duration::from_secs(1)), None);
+
+        bucket.update(0, Duration::from_secs(30), now + Duration::from_secs(1));
+        assert_eq!(
+            bucket.try_acquire(now + Duration::from_secs(11)),
+            Some(Duration::from_secs(20))
+        );
+        assert_eq!(bucket.try_acquire(now + Duration::from_secs(31)), None);
+    }
+
+    #[test]
+    fn test_token_bucket_without_limit() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(None, now);
+        assert_eq!(bucket.try_acquire(now), None);
+        bucket.update(0, Duration::from_secs(5), now);
+        assert_eq!(bucket.try_acquire(now), Some(Duration::from_secs(5)));
+        assert_eq!(bucket.try_acquire(now + Duration::from_secs(5)), None);
+    }
+
+    #[test]
+    fn test_rate_limiter_tariff_groups() {
+        let get_candles = "tinkoff.public.invest.api.contract.v1.MarketDataService/GetCandles";
+        let get_last_prices =
+            "tinkoff.public.invest.api.contract.v1.MarketDataService/GetLastPrices";
+        let get_accounts = "tinkoff.public.invest.api.contract.v1.UsersService/GetAccounts";
+        let post_order = "tinkoff.public.invest.api.contract.v1.OrdersService/PostOrder";
+        let rate_limiter = RateLimiter::new(&HashMap::from([(Service::Orders, 10)]));
+        rate_limiter.set_tariff(&GetUserTariffResponse {
+            unary_limits: vec![
+                UnaryLimit {
+                    limit_per_minute: 600,
+                    methods: vec![get_candles.into()],
+                    ..Default::default()
+                },
+                UnaryLimit {
+                    limit_per_minute: 300,
+                    methods: vec![get_last_prices.into(), post_order.into()],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        let state = rate_limiter.state.lock().unwrap();
+        assert_eq!(
+            state.key(&format!("/{get_candles}")),
+            Some(BucketKey::TariffGroup(0))
+        );
+        assert_eq!(
+            state.key(&format!("/{get_last_prices}")),
+            Some(BucketKey::TariffGroup(1))
+        );
+        assert_eq!(
+            state.key(&format!("/{post_order}")),
+            Some(BucketKey::Service(Service::Orders))
+        );
+        assert_eq!(
+            state.key(&format!("/{get_accounts}")),
+            Some(BucketKey::Service(Service::Users))
+        );
+        assert_eq!(state.key("/unknown.Service/Method"), None);
+    }
+}
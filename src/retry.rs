@@ -0,0 +1,141 @@
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use tonic::{Code, Response, Status};
+
+/// Политика повтора запросов при временных ошибках.
+///
+/// Повторяются запросы на чтение, выставление и отмену заявок, удаление группы избранного
+/// и закрытие счёта песочницы: повтор выставления заявки отправляет тот же `order_id`,
+/// поэтому заявка не будет выставлена дважды, а повторная отмена или удаление ничего не меняет.
+/// Создание группы избранного, открытие счёта и пополнение песочницы, а также запуск
+/// формирования отчётов не повторяются:
+/// у этих запросов нет ключа идемпотентности, и повтор может создать дубликат.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    retryable_codes: Vec<Code>,
+}
+
+impl RetryPolicy {
+    /// 3 попытки, задержка от 100 мс до 5 с, повтор при `UNAVAILABLE`, `INTERNAL` и `DEADLINE_EXCEEDED`.
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            retryable_codes: vec![Code::Unavailable, Code::Internal, Code::DeadlineExceeded],
+        }
+    }
+
+    /// Без повторов.
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::new()
+        }
+    }
+
+    /// Максимальное количество попыток, включая первую.
+    #[inline]
+    pub fn set_max_attempts(&mut self, max_attempts: u32) -> &mut Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Задержка перед первым повтором. Удваивается с каждой попыткой.
+    #[inline]
+    pub fn set_initial_backoff(&mut self, initial_backoff: Duration) -> &mut Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    #[inline]
+    pub fn set_max_backoff(&mut self, max_backoff: Duration) -> &mut Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    #[inline]
+    pub fn set_retryable_codes(&mut self, retryable_codes: &[Code]) -> &mut Self {
+        self.retryable_codes = retryable_codes.to_vec();
+        self
+    }
+
+    #[inline]
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    #[inline]
+    pub fn is_retryable(&self, status: &Status) -> bool {
+        self.retryable_codes.contains(&status.code())
+    }
+
+    /// Задержка перед повтором `attempt` (начиная с 1): экспоненциальная, со случайной
+    /// составляющей в пределах половины задержки.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+        let jitter = RandomState::new().build_hasher().finish() as f64 / u64::MAX as f64;
+        backoff / 2 + backoff.mul_f64(jitter / 2.0)
+    }
+
+    /// Выполняет запрос, повторяя его согласно политике.
+    pub(crate) async fn call<C, R, T, F, Fut>(
+        &self,
+        client: &C,
+        request: R,
+        f: F,
+    ) -> Result<Response<T>, Status>
+    where
+        C: Clone,
+        R: Clone,
+        F: Fn(C, R) -> Fut,
+        Fut: Future<Output = Result<Response<T>, Status>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match f(client.clone(), request.clone()).await {
+                Err(status) if attempt < self.max_attempts && self.is_retryable(&status) => {
+                    tokio::time::sleep(self.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::RetryPolicy;
+
+    #[test]
+    fn test_backoff() {
+        let mut retry_policy = RetryPolicy::new();
+        retry_policy
+            .set_initial_backoff(Duration::from_millis(100))
+            .set_max_backoff(Duration::from_millis(300));
+        for (attempt, expected) in [(1, 100), (2, 200), (3, 300), (10, 300)] {
+            let backoff = retry_policy.backoff(attempt);
+            assert!(backoff >= Duration::from_millis(expected / 2));
+            assert!(backoff <= Duration::from_millis(expected));
+        }
+    }
+}
@@ -1,4 +1,3 @@
-use std::error::Error;
 use tinkoff_invest_types as tit;
 use tinkoff_invest_types::market_data_stream_service_client::MarketDataStreamServiceClient;
 use tokio::sync::broadcast;
@@ -9,7 +8,7 @@ use tokio_stream::wrappers::UnboundedReceiverStream;
 use tonic::transport::Endpoint;
 use tonic::{service::Interceptor, transport::Channel};
 
-use crate::{TinkoffInvest, TinkoffInvestError, enums, traits};
+use crate::{Error, TinkoffInvest, TinkoffInvestError, enums, traits};
 
 pub struct MarketDataStreamBuilder<I>
 where
@@ -39,7 +38,7 @@ where
         self
     }
 
-    pub async fn build(self) -> Result<MarketDataStream, Box<dyn Error>> {
+    pub async fn build(self) -> Result<MarketDataStream, Error> {
         let channel = if let Some(channel) = self.channel {
             channel
         } else if let Some(endpoint) = self.endpoint {
@@ -125,7 +124,7 @@ impl MarketDataStream {
         &mut self,
         instruments: &[T],
         interval: &enums::CandlestickInterval,
-    ) -> Result<(), Box<dyn Error>>
+    ) -> Result<(), Error>
     where
         T: traits::ToUid,
     {
@@ -152,7 +151,9 @@ impl MarketDataStream {
             payload: Some(payload),
         };
 
-        self.sender.send(request)?;
+        self.sender
+            .send(request)
+            .map_err(|_| TinkoffInvestError::MarketDataStreamClosed)?;
 
         Ok(())
     }
@@ -161,7 +162,7 @@ impl MarketDataStream {
         &mut self,
         instruments: &[T],
         interval: &enums::CandlestickInterval,
-    ) -> Result<(), Box<dyn Error>>
+    ) -> Result<(), Error>
     where
         T: traits::ToUid,
     {
@@ -188,7 +189,9 @@ impl MarketDataStream {
             payload: Some(payload),
         };
 
-        self.sender.send(request)?;
+        self.sender
+            .send(request)
+            .map_err(|_| TinkoffInvestError::MarketDataStreamClosed)?;
 
         Ok(())
     }
@@ -197,7 +200,7 @@ impl MarketDataStream {
         &mut self,
         instruments: &[T],
         depth: u32,
-    ) -> Result<(), Box<dyn Error>>
+    ) -> Result<(), Error>
     where
         T: traits::ToUid,
     {
@@ -226,7 +229,9 @@ impl MarketDataStream {
             payload: Some(payload),
         };
 
-        self.sender.send(request)?;
+        self.sender
+            .send(request)
+            .map_err(|_| TinkoffInvestError::MarketDataStreamClosed)?;
 
         Ok(())
     }
@@ -235,7 +240,7 @@ impl MarketDataStream {
         &mut self,
         instruments: &[T],
         depth: u32,
-    ) -> Result<(), Box<dyn Error>>
+    ) -> Result<(), Error>
     where
         T: traits::ToUid,
     {
@@ -264,7 +269,9 @@ impl MarketDataStream {
             payload: Some(payload),
         };
 
-        self.sender.send(request)?;
+        self.sender
+            .send(request)
+            .map_err(|_| TinkoffInvestError::MarketDataStreamClosed)?;
 
         Ok(())
     }
@@ -0,0 +1,89 @@
+use tinkoff_invest_types as tit;
+
+use crate::{enums, traits, types};
+
+/// Актив: эмитент, валюта, товар или индекс, к которому относятся инструменты.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    /// Идентификатор актива. `None`, если сервер вернул пустой или некорректный UID.
+    pub uid: Option<types::Uid>,
+    pub asset_type: enums::AssetType,
+    pub name: String,
+    /// Короткое наименование актива. Заполняется только в `TinkoffInvest::asset_by`.
+    pub name_brief: Option<String>,
+    /// Описание актива. Заполняется только в `TinkoffInvest::asset_by`.
+    pub description: Option<String>,
+    /// Бренд. Заполняется только в `TinkoffInvest::asset_by`.
+    pub brand: Option<String>,
+    /// Инструменты актива.
+    pub instruments: Vec<AssetInstrument>,
+}
+
+#[inline]
+fn optional_string(value: String) -> Option<String> {
+    if !value.is_empty() { Some(value) } else { None }
+}
+
+impl From<tit::Asset> for Asset {
+    fn from(value: tit::Asset) -> Self {
+        let asset_type = value.r#type().into();
+        Self {
+            uid: value.uid.parse().ok(),
+            asset_type,
+            name: value.name,
+            name_brief: None,
+            description: None,
+            brand: None,
+            instruments: value.instruments.into_iter().map(|x| x.into()).collect(),
+        }
+    }
+}
+
+impl From<tit::AssetFull> for Asset {
+    fn from(value: tit::AssetFull) -> Self {
+        let asset_type = value.r#type().into();
+        Self {
+            uid: value.uid.parse().ok(),
+            asset_type,
+            name: value.name,
+            name_brief: optional_string(value.name_brief),
+            description: optional_string(value.description),
+            brand: value.brand.and_then(|x| optional_string(x.name)),
+            instruments: value.instruments.into_iter().map(|x| x.into()).collect(),
+        }
+    }
+}
+
+/// Инструмент актива.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetInstrument {
+    /// Идентификатор инструмента. `None`, если сервер вернул пустой или некорректный UID.
+    pub uid: Option<types::Uid>,
+    /// Идентификатор позиции.
+    pub position_uid: Option<types::Uid>,
+    pub figi: types::Figi,
+    pub ticker: types::Ticker,
+    pub class_code: enums::ClassCode,
+    /// Тип инструмента. `None` для типов, не поддерживаемых `enums::InstrumentType`.
+    pub instrument_type: Option<enums::InstrumentType>,
+}
+
+impl From<tit::AssetInstrument> for AssetInstrument {
+    fn from(value: tit::AssetInstrument) -> Self {
+        let instrument_type = value.instrument_kind().try_into().ok();
+        Self {
+            uid: value.uid.parse().ok(),
+            position_uid: value.position_uid.parse().ok(),
+            figi: value.figi.into(),
+            ticker: value.ticker.into(),
+            class_code: value.class_code.into(),
+            instrument_type,
+        }
+    }
+}
+
+impl traits::ToFigi for &AssetInstrument {
+    fn to_figi(&self) -> types::Figi {
+        self.figi.clone()
+    }
+}
@@ -0,0 +1,104 @@
+use tinkoff_invest_types::get_asset_fundamentals_response::StatisticResponse;
+
+use crate::{enums, types};
+
+/// Фундаментальные показатели актива.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetFundamentals {
+    /// Идентификатор актива. `None`, если сервер вернул пустой или некорректный UID.
+    pub asset_uid: Option<types::Uid>,
+    pub currency: enums::Currency,
+    /// Рыночная капитализация.
+    pub market_capitalization: f64,
+    /// Максимум цены за год.
+    pub high_price_last_52_weeks: f64,
+    /// Минимум цены за год.
+    pub low_price_last_52_weeks: f64,
+    pub beta: f64,
+    /// Доля акций в свободном обращении.
+    pub free_float: f64,
+    /// Количество акций в обращении.
+    pub shares_outstanding: f64,
+    /// Выручка за 12 месяцев.
+    pub revenue_ttm: f64,
+    /// EBITDA за 12 месяцев.
+    pub ebitda_ttm: f64,
+    /// Чистая прибыль за 12 месяцев.
+    pub net_income_ttm: f64,
+    /// Прибыль на акцию за 12 месяцев.
+    pub eps_ttm: f64,
+    /// Свободный денежный поток за 12 месяцев.
+    pub free_cash_flow_ttm: f64,
+    /// P/E.
+    pub pe_ratio_ttm: f64,
+    /// P/S.
+    pub price_to_sales_ttm: f64,
+    /// P/B.
+    pub price_to_book_ttm: f64,
+    /// Рыночная стоимость компании (EV).
+    pub total_enterprise_value_mrq: f64,
+    /// EV/EBITDA.
+    pub ev_to_ebitda_mrq: f64,
+    /// Маржа чистой прибыли.
+    pub net_margin_mrq: f64,
+    /// Рентабельность собственного капитала.
+    pub roe: f64,
+    /// Рентабельность активов.
+    pub roa: f64,
+    /// Рентабельность инвестированного капитала.
+    pub roic: f64,
+    /// Сумма краткосрочных и долгосрочных обязательств.
+    pub total_debt_mrq: f64,
+    /// Debt/Equity.
+    pub total_debt_to_equity_mrq: f64,
+    /// Net Debt/EBITDA.
+    pub net_debt_to_ebitda: f64,
+    /// Форвардная дивидендная доходность в %.
+    pub forward_annual_dividend_yield: f64,
+    /// Дивидендная доходность за 12 месяцев в %.
+    pub dividend_yield_daily_ttm: f64,
+    /// Выплаченные дивиденды за 12 месяцев.
+    pub dividend_rate_ttm: f64,
+    pub dividends_per_share: f64,
+    /// Доля чистой прибыли, направляемая на дивиденды.
+    pub dividend_payout_ratio_fy: f64,
+    pub ex_dividend_date: Option<types::DateTime>,
+}
+
+impl From<StatisticResponse> for AssetFundamentals {
+    fn from(value: StatisticResponse) -> Self {
+        Self {
+            asset_uid: value.asset_uid.parse().ok(),
+            currency: value.currency.into(),
+            market_capitalization: value.market_capitalization,
+            high_price_last_52_weeks: value.high_price_last_52_weeks,
+            low_price_last_52_weeks: value.low_price_last_52_weeks,
+            beta: value.beta,
+            free_float: value.free_float,
+            shares_outstanding: value.shares_outstanding,
+            revenue_ttm: value.revenue_ttm,
+            ebitda_ttm: value.ebitda_ttm,
+            net_income_ttm: value.net_income_ttm,
+            eps_ttm: value.eps_ttm,
+            free_cash_flow_ttm: value.free_cash_flow_ttm,
+            pe_ratio_ttm: value.pe_ratio_ttm,
+            price_to_sales_ttm: value.price_to_sales_ttm,
+            price_to_book_ttm: value.price_to_book_ttm,
+            total_enterprise_value_mrq: value.total_enterprise_value_mrq,
+            ev_to_ebitda_mrq: value.ev_to_ebitda_mrq,
+            net_margin_mrq: value.net_margin_mrq,
+            roe: value.roe,
+            roa: value.roa,
+            roic: value.roic,
+            total_debt_mrq: value.total_debt_mrq,
+            total_debt_to_equity_mrq: value.total_debt_to_equity_mrq,
+            net_debt_to_ebitda: value.net_debt_to_ebitda,
+            forward_annual_dividend_yield: value.forward_annual_dividend_yield,
+            dividend_yield_daily_ttm: value.dividend_yield_daily_ttm,
+            dividend_rate_ttm: value.dividend_rate_ttm,
+            dividends_per_share: value.dividends_per_share,
+            dividend_payout_ratio_fy: value.dividend_payout_ratio_fy,
+            ex_dividend_date: value.ex_dividend_date.map(|x| x.into()),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use tinkoff_invest_types::get_bond_events_response;
+
+use crate::{enums, types};
+
+/// Событие по облигации: выплата купона, оферта, погашение или конвертация.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BondEvent {
+    pub kind: enums::BondEventKind,
+    /// Номер события для данного типа события.
+    pub event_number: u32,
+    pub event_date: Option<types::DateTime>,
+    /// Дата фиксации владельцев для участия в событии.
+    pub fix_date: Option<types::DateTime>,
+    /// Дата выплаты.
+    pub pay_date: Option<types::DateTime>,
+    /// Дата реального исполнения обязательства.
+    pub real_pay_date: Option<types::DateTime>,
+    /// Дата дефолта, если применимо.
+    pub default_date: Option<types::DateTime>,
+    /// Выплата на одну облигацию.
+    pub pay_one_bond: Option<types::Money>,
+    /// Выплаты на все бумаги, задействованные в событии.
+    pub money_flow: Option<types::Money>,
+    /// Ставка купона, доля номинала, цена выкупа или коэффициент конвертации.
+    pub value: Option<types::MoneyValue>,
+    /// Признак исполнения.
+    pub execution: String,
+    pub note: String,
+    /// Начало купонного периода.
+    pub coupon_start_date: Option<types::DateTime>,
+    /// Окончание купонного периода.
+    pub coupon_end_date: Option<types::DateTime>,
+    /// Купонный период в днях.
+    pub coupon_period: u32,
+    /// Ставка купона, процентов годовых.
+    pub coupon_interest_rate: Option<types::MoneyValue>,
+}
+
+impl From<get_bond_events_response::BondEvent> for BondEvent {
+    fn from(value: get_bond_events_response::BondEvent) -> Self {
+        let kind = value.event_type().into();
+        Self {
+            kind,
+            event_number: value.event_number as u32,
+            event_date: value.event_date.map(|x| x.into()),
+            fix_date: value.fix_date.map(|x| x.into()),
+            pay_date: value.pay_date.map(|x| x.into()),
+            real_pay_date: value.real_pay_date.map(|x| x.into()),
+            default_date: value.default_date.map(|x| x.into()),
+            pay_one_bond: value.pay_one_bond.map(|x| x.into()),
+            money_flow: value.money_flow_val.map(|x| x.into()),
+            value: value.value.map(|x| x.into()),
+            execution: value.execution,
+            note: value.note,
+            coupon_start_date: value.coupon_start_date.map(|x| x.into()),
+            coupon_end_date: value.coupon_end_date.map(|x| x.into()),
+            coupon_period: value.coupon_period as u32,
+            coupon_interest_rate: value.coupon_interest_rate.map(|x| x.into()),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use tinkoff_invest_types as tit;
+
+use crate::{enums, types};
+
+/// Сделка из брокерского отчёта.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrokerReportItem {
+    /// Номер сделки.
+    pub trade_id: String,
+    /// Номер поручения.
+    pub order_id: String,
+    pub figi: types::Figi,
+    /// Признак исполнения.
+    pub execute_sign: String,
+    /// Дата и время заключения сделки.
+    pub trade_datetime: Option<types::DateTime>,
+    /// Торговая площадка.
+    pub exchange: String,
+    pub class_code: enums::ClassCode,
+    /// Вид сделки.
+    pub direction: String,
+    pub name: String,
+    pub ticker: types::Ticker,
+    /// Цена за единицу.
+    pub price: Option<types::Money>,
+    /// Количество инструментов в штуках.
+    pub quantity: u64,
+    /// Сумма сделки без учёта НКД.
+    pub order_amount: Option<types::Money>,
+    /// НКД.
+    pub accrued_interest: Option<types::MoneyValue>,
+    /// Сумма сделки.
+    pub total_order_amount: Option<types::Money>,
+    /// Комиссия брокера.
+    pub broker_commission: Option<types::Money>,
+    /// Комиссия биржи.
+    pub exchange_commission: Option<types::Money>,
+    /// Комиссия клирингового центра.
+    pub exchange_clearing_commission: Option<types::Money>,
+    /// Ставка РЕПО в %.
+    pub repo_rate: Option<types::MoneyValue>,
+    /// Контрагент или брокер.
+    pub party: String,
+    /// Дата расчётов в денежных средствах.
+    pub clear_value_date: Option<types::DateTime>,
+    /// Дата поставки ценных бумаг.
+    pub sec_value_date: Option<types::DateTime>,
+    /// Статус брокера.
+    pub broker_status: String,
+    /// Тип расчёта по сделке.
+    pub delivery_type: String,
+}
+
+impl From<tit::BrokerReport> for BrokerReportItem {
+    fn from(value: tit::BrokerReport) -> Self {
+        Self {
+            trade_id: value.trade_id,
+            order_id: value.order_id,
+            figi: value.figi.into(),
+            execute_sign: value.execute_sign,
+            trade_datetime: value.trade_datetime.map(|x| x.into()),
+            exchange: value.exchange,
+            class_code: value.class_code.into(),
+            direction: value.direction,
+            name: value.name,
+            ticker: value.ticker.into(),
+            price: value.price.map(|x| x.into()),
+            quantity: value.quantity as u64,
+            order_amount: value.order_amount.map(|x| x.into()),
+            accrued_interest: value.aci_value.map(|x| x.into()),
+            total_order_amount: value.total_order_amount.map(|x| x.into()),
+            broker_commission: value.broker_commission.map(|x| x.into()),
+            exchange_commission: value.exchange_commission.map(|x| x.into()),
+            exchange_clearing_commission: value.exchange_clearing_commission.map(|x| x.into()),
+            repo_rate: value.repo_rate.map(|x| x.into()),
+            party: value.party,
+            clear_value_date: value.clear_value_date.map(|x| x.into()),
+            sec_value_date: value.sec_value_date.map(|x| x.into()),
+            broker_status: value.broker_status,
+            delivery_type: value.delivery_type,
+        }
+    }
+}
+
+/// Выплата дивидендов иностранным эмитентом из отчёта.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DividendForeignIssuerItem {
+    /// Дата фиксации реестра.
+    pub record_date: Option<types::DateTime>,
+    /// Дата выплаты.
+    pub payment_date: Option<types::DateTime>,
+    pub security_name: String,
+    pub isin: types::Isin,
+    /// Страна эмитента.
+    pub issuer_country: String,
+    /// Количество ценных бумаг.
+    pub quantity: u64,
+    /// Выплаты на одну бумагу.
+    pub dividend: Option<types::MoneyValue>,
+    /// Комиссия внешних платёжных агентов.
+    pub external_commission: Option<types::MoneyValue>,
+    /// Сумма до удержания налога.
+    pub dividend_gross: Option<types::MoneyValue>,
+    /// Сумма налога, удержанного агентом.
+    pub tax: Option<types::MoneyValue>,
+    /// Итоговая сумма выплаты.
+    pub dividend_amount: Option<types::MoneyValue>,
+    pub currency: enums::Currency,
+}
+
+impl From<tit::DividendsForeignIssuerReport> for DividendForeignIssuerItem {
+    fn from(value: tit::DividendsForeignIssuerReport) -> Self {
+        Self {
+            record_date: value.record_date.map(|x| x.into()),
+            payment_date: value.payment_date.map(|x| x.into()),
+            security_name: value.security_name,
+            isin: value.isin.into(),
+            issuer_country: value.issuer_country,
+            quantity: value.quantity as u64,
+            dividend: value.dividend.map(|x| x.into()),
+            external_commission: value.external_commission.map(|x| x.into()),
+            dividend_gross: value.dividend_gross.map(|x| x.into()),
+            tax: value.tax.map(|x| x.into()),
+            dividend_amount: value.dividend_amount.map(|x| x.into()),
+            currency: value.currency.into(),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use tinkoff_invest_types as tit;
+
+use crate::{enums, types};
+
+/// Купон облигации.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coupon {
+    pub figi: types::Figi,
+    /// Дата выплаты купона.
+    pub coupon_date: Option<types::DateTime>,
+    /// Номер купона.
+    pub coupon_number: u64,
+    /// Дата фиксации реестра для выплаты купона.
+    pub fix_date: Option<types::DateTime>,
+    /// Выплата на одну облигацию.
+    pub pay_one_bond: Option<types::Money>,
+    pub coupon_type: enums::CouponType,
+    /// Начало купонного периода.
+    pub coupon_start_date: Option<types::DateTime>,
+    /// Окончание купонного периода.
+    pub coupon_end_date: Option<types::DateTime>,
+    /// Купонный период в днях.
+    pub coupon_period: u32,
+}
+
+impl From<tit::Coupon> for Coupon {
+    fn from(value: tit::Coupon) -> Self {
+        let coupon_type = value.coupon_type().into();
+        Self {
+            figi: value.figi.into(),
+            coupon_date: value.coupon_date.map(|x| x.into()),
+            coupon_number: value.coupon_number as u64,
+            fix_date: value.fix_date.map(|x| x.into()),
+            pay_one_bond: value.pay_one_bond.map(|x| x.into()),
+            coupon_type,
+            coupon_start_date: value.coupon_start_date.map(|x| x.into()),
+            coupon_end_date: value.coupon_end_date.map(|x| x.into()),
+            coupon_period: value.coupon_period as u32,
+        }
+    }
+}
+
+/// Начисление накопленного купонного дохода.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccruedInterest {
+    pub date: Option<types::DateTime>,
+    /// Величина выплаты.
+    pub value: Option<types::MoneyValue>,
+    /// Величина выплаты в процентах от номинала.
+    pub value_percent: Option<types::MoneyValue>,
+    /// Номинал облигации.
+    pub nominal: Option<types::MoneyValue>,
+}
+
+impl From<tit::AccruedInterest> for AccruedInterest {
+    fn from(value: tit::AccruedInterest) -> Self {
+        Self {
+            date: value.date.map(|x| x.into()),
+            value: value.value.map(|x| x.into()),
+            value_percent: value.value_percent.map(|x| x.into()),
+            nominal: value.nominal.map(|x| x.into()),
+        }
+    }
+}
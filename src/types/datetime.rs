@@ -10,17 +10,15 @@ pub struct DateTime {
 
 impl PartialOrd for DateTime {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.seconds > other.seconds {
-            Some(Ordering::Greater)
-        } else if self.seconds < other.seconds {
-            Some(Ordering::Less)
-        } else if self.nanoseconds > other.nanoseconds {
-            Some(Ordering::Greater)
-        } else if self.nanoseconds < other.nanoseconds {
-            Some(Ordering::Less)
-        } else {
-            Some(Ordering::Equal)
-        }
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.seconds
+            .cmp(&other.seconds)
+            .then(self.nanoseconds.cmp(&other.nanoseconds))
     }
 }
 
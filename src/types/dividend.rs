@@ -0,0 +1,44 @@
+use tinkoff_invest_types as tit;
+
+use crate::types;
+
+/// Выплата дивидендов.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dividend {
+    /// Величина дивиденда на 1 ценную бумагу.
+    pub dividend_net: Option<types::Money>,
+    /// Дата фактических выплат.
+    pub payment_date: Option<types::DateTime>,
+    /// Дата объявления дивидендов.
+    pub declared_date: Option<types::DateTime>,
+    /// Последний день (включительно) покупки для получения выплаты.
+    pub last_buy_date: Option<types::DateTime>,
+    /// Дата фиксации реестра.
+    pub record_date: Option<types::DateTime>,
+    /// Тип выплаты, например `Regular Cash` или `Cancelled`.
+    pub dividend_type: String,
+    /// Регулярность выплаты, например `Annual` или `Semi-Anl`.
+    pub regularity: String,
+    /// Цена закрытия инструмента на момент `ex_dividend_date`.
+    pub close_price: Option<types::Money>,
+    /// Величина доходности.
+    pub yield_value: Option<types::MoneyValue>,
+    pub created_at: Option<types::DateTime>,
+}
+
+impl From<tit::Dividend> for Dividend {
+    fn from(value: tit::Dividend) -> Self {
+        Self {
+            dividend_net: value.dividend_net.map(|x| x.into()),
+            payment_date: value.payment_date.map(|x| x.into()),
+            declared_date: value.declared_date.map(|x| x.into()),
+            last_buy_date: value.last_buy_date.map(|x| x.into()),
+            record_date: value.record_date.map(|x| x.into()),
+            dividend_type: value.dividend_type,
+            regularity: value.regularity,
+            close_price: value.close_price.map(|x| x.into()),
+            yield_value: value.yield_value.map(|x| x.into()),
+            created_at: value.created_at.map(|x| x.into()),
+        }
+    }
+}
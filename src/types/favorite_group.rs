@@ -0,0 +1,23 @@
+use tinkoff_invest_types::get_favorite_groups_response;
+
+/// Группа избранных инструментов.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FavoriteGroup {
+    pub id: String,
+    pub name: String,
+    /// Цвет группы в формате RGB.
+    pub color: String,
+    /// Количество инструментов в группе.
+    pub size: u32,
+}
+
+impl From<get_favorite_groups_response::FavoriteGroup> for FavoriteGroup {
+    fn from(value: get_favorite_groups_response::FavoriteGroup) -> Self {
+        Self {
+            id: value.group_id,
+            name: value.group_name,
+            color: value.color,
+            size: value.size as u32,
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use tinkoff_invest_types::{self as tit, get_forecast_response};
+
+use crate::{enums, types};
+
+/// Прогнозы аналитиков по инструменту.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forecast {
+    /// Согласованный прогноз.
+    pub consensus: Option<ForecastConsensus>,
+    /// Прогнозы отдельных компаний.
+    pub targets: Vec<ForecastTarget>,
+}
+
+impl From<tit::GetForecastResponse> for Forecast {
+    fn from(value: tit::GetForecastResponse) -> Self {
+        Self {
+            consensus: value.consensus.map(|x| x.into()),
+            targets: value.targets.into_iter().map(|x| x.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForecastConsensus {
+    /// Идентификатор инструмента. `None`, если сервер вернул пустой или некорректный UID.
+    pub instrument_uid: Option<types::Uid>,
+    pub ticker: types::Ticker,
+    pub recommendation: enums::Recommendation,
+    pub currency: enums::Currency,
+    pub current_price: Option<types::MoneyValue>,
+    /// Согласованная целевая цена.
+    pub target_price: Option<types::MoneyValue>,
+    pub min_target_price: Option<types::MoneyValue>,
+    pub max_target_price: Option<types::MoneyValue>,
+    /// Изменение цены до целевой.
+    pub price_change: Option<types::MoneyValue>,
+    /// Изменение цены до целевой в %.
+    pub price_change_rel: Option<types::MoneyValue>,
+}
+
+impl From<get_forecast_response::ConsensusItem> for ForecastConsensus {
+    fn from(value: get_forecast_response::ConsensusItem) -> Self {
+        let recommendation = value.recommendation().into();
+        Self {
+            instrument_uid: value.uid.parse().ok(),
+            ticker: value.ticker.into(),
+            recommendation,
+            currency: value.currency.into(),
+            current_price: value.current_price.map(|x| x.into()),
+            target_price: value.consensus.map(|x| x.into()),
+            min_target_price: value.min_target.map(|x| x.into()),
+            max_target_price: value.max_target.map(|x| x.into()),
+            price_change: value.price_change.map(|x| x.into()),
+            price_change_rel: value.price_change_rel.map(|x| x.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForecastTarget {
+    /// Идентификатор инструмента. `None`, если сервер вернул пустой или некорректный UID.
+    pub instrument_uid: Option<types::Uid>,
+    pub ticker: types::Ticker,
+    /// Компания, давшая прогноз.
+    pub company: String,
+    pub recommendation: enums::Recommendation,
+    pub recommendation_date: Option<types::DateTime>,
+    pub currency: enums::Currency,
+    pub current_price: Option<types::MoneyValue>,
+    pub target_price: Option<types::MoneyValue>,
+    /// Изменение цены до целевой.
+    pub price_change: Option<types::MoneyValue>,
+    /// Изменение цены до целевой в %.
+    pub price_change_rel: Option<types::MoneyValue>,
+}
+
+impl From<get_forecast_response::TargetItem> for ForecastTarget {
+    fn from(value: get_forecast_response::TargetItem) -> Self {
+        let recommendation = value.recommendation().into();
+        Self {
+            instrument_uid: value.uid.parse().ok(),
+            ticker: value.ticker.into(),
+            company: value.company,
+            recommendation,
+            recommendation_date: value.recommendation_date.map(|x| x.into()),
+            currency: value.currency.into(),
+            current_price: value.current_price.map(|x| x.into()),
+            target_price: value.target_price.map(|x| x.into()),
+            price_change: value.price_change.map(|x| x.into()),
+            price_change_rel: value.price_change_rel.map(|x| x.into()),
+        }
+    }
+}
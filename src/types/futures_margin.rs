@@ -0,0 +1,27 @@
+use tinkoff_invest_types as tit;
+
+use crate::types;
+
+/// Гарантийное обеспечение по фьючерсу.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuturesMargin {
+    /// Гарантийное обеспечение при покупке.
+    pub initial_margin_on_buy: Option<types::Money>,
+    /// Гарантийное обеспечение при продаже.
+    pub initial_margin_on_sell: Option<types::Money>,
+    /// Шаг цены.
+    pub min_price_increment: Option<types::MoneyValue>,
+    /// Стоимость шага цены.
+    pub min_price_increment_amount: Option<types::MoneyValue>,
+}
+
+impl From<tit::GetFuturesMarginResponse> for FuturesMargin {
+    fn from(value: tit::GetFuturesMarginResponse) -> Self {
+        Self {
+            initial_margin_on_buy: value.initial_margin_on_buy.map(|x| x.into()),
+            initial_margin_on_sell: value.initial_margin_on_sell.map(|x| x.into()),
+            min_price_increment: value.min_price_increment.map(|x| x.into()),
+            min_price_increment_amount: value.min_price_increment_amount.map(|x| x.into()),
+        }
+    }
+}
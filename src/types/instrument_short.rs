@@ -0,0 +1,106 @@
+use tinkoff_invest_types as tit;
+
+use crate::{enums, traits, types};
+
+/// Краткая информация об инструменте, найденном поиском.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstrumentShort {
+    pub uid: types::Uid,
+    /// Идентификатор позиции.
+    pub position_uid: Option<types::Uid>,
+    pub figi: types::Figi,
+    pub isin: Option<types::Isin>,
+    pub ticker: types::Ticker,
+    pub class_code: enums::ClassCode,
+    /// Тип инструмента. `None` для типов, не поддерживаемых `enums::InstrumentType`.
+    pub instrument_type: Option<enums::InstrumentType>,
+    pub name: String,
+    pub lot_size: u64,
+    pub is_api_trade_available: bool,
+    /// Доступен для ИИС.
+    pub is_for_iis: bool,
+    /// Доступен только для квалифицированных инвесторов.
+    pub is_for_qualified_investor: bool,
+    /// Доступен для торговли в выходные.
+    pub is_weekend_available: bool,
+    /// Дата первой минутной свечи.
+    pub first_1min_candle_date: Option<types::DateTime>,
+    /// Дата первой дневной свечи.
+    pub first_1day_candle_date: Option<types::DateTime>,
+}
+
+/// Ошибка, если сервер вернул пустой или некорректный UID инструмента.
+impl TryFrom<tit::InstrumentShort> for InstrumentShort {
+    type Error = uuid::Error;
+
+    fn try_from(value: tit::InstrumentShort) -> Result<Self, Self::Error> {
+        let instrument_type = value.instrument_kind().try_into().ok();
+        let isin = if !value.isin.is_empty() {
+            Some(value.isin.into())
+        } else {
+            None
+        };
+        Ok(Self {
+            uid: value.uid.parse()?,
+            position_uid: value.position_uid.parse().ok(),
+            figi: value.figi.into(),
+            isin,
+            ticker: value.ticker.into(),
+            class_code: value.class_code.into(),
+            instrument_type,
+            name: value.name,
+            lot_size: value.lot as u64,
+            is_api_trade_available: value.api_trade_available_flag,
+            is_for_iis: value.for_iis_flag,
+            is_for_qualified_investor: value.for_qual_investor_flag,
+            is_weekend_available: value.weekend_flag,
+            first_1min_candle_date: value.first_1min_candle_date.map(|x| x.into()),
+            first_1day_candle_date: value.first_1day_candle_date.map(|x| x.into()),
+        })
+    }
+}
+
+impl traits::ToUid for &InstrumentShort {
+    fn to_uid(&self) -> types::Uid {
+        self.uid.clone()
+    }
+}
+
+impl traits::ToFigi for &InstrumentShort {
+    fn to_figi(&self) -> types::Figi {
+        self.figi.clone()
+    }
+}
+
+impl traits::ToClassCode for &InstrumentShort {
+    fn to_class_code(&self) -> enums::ClassCode {
+        self.class_code.clone()
+    }
+}
+
+/// Фильтр поиска инструментов.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InstrumentsFilter {
+    pub instrument_type: Option<enums::InstrumentType>,
+    /// Только инструменты, доступные для торговли через API.
+    pub is_api_trade_available: Option<bool>,
+}
+
+impl InstrumentsFilter {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_instrument_type(&mut self, instrument_type: enums::InstrumentType) -> &mut Self {
+        self.instrument_type = Some(instrument_type);
+        self
+    }
+
+    #[inline]
+    pub fn set_api_trade_available(&mut self, value: bool) -> &mut Self {
+        self.is_api_trade_available = Some(value);
+        self
+    }
+}
@@ -0,0 +1,55 @@
+use tinkoff_invest_types as tit;
+
+use crate::{TinkoffInvestError, enums, types};
+
+/// Цена последней сделки по инструменту.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LastPrice {
+    /// Цена за 1 инструмент.
+    pub price: types::MoneyValue,
+    /// Дата и время получения цены.
+    pub datetime: Option<types::DateTime>,
+    /// Тип цены.
+    pub price_type: enums::LastPriceType,
+}
+
+/// Ошибка, если сервер не вернул цену.
+impl TryFrom<tit::LastPrice> for LastPrice {
+    type Error = TinkoffInvestError;
+
+    fn try_from(value: tit::LastPrice) -> Result<Self, Self::Error> {
+        let price_type = value.last_price_type().into();
+        Ok(Self {
+            price: value.price.ok_or(TinkoffInvestError::PriceNotSet)?.into(),
+            datetime: value.time.map(|x| x.into()),
+            price_type,
+        })
+    }
+}
+
+/// Цена закрытия торговой сессии по инструменту.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClosePrice {
+    /// Цена закрытия торговой сессии.
+    pub price: types::MoneyValue,
+    /// Дата торгов.
+    pub datetime: Option<types::DateTime>,
+    /// Цена последней сделки вечерней сессии.
+    pub evening_session_price: Option<types::MoneyValue>,
+    /// Дата цены вечерней сессии.
+    pub evening_session_datetime: Option<types::DateTime>,
+}
+
+/// Ошибка, если сервер не вернул цену закрытия.
+impl TryFrom<tit::InstrumentClosePriceResponse> for ClosePrice {
+    type Error = TinkoffInvestError;
+
+    fn try_from(value: tit::InstrumentClosePriceResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            price: value.price.ok_or(TinkoffInvestError::PriceNotSet)?.into(),
+            datetime: value.time.map(|x| x.into()),
+            evening_session_price: value.evening_session_price.map(|x| x.into()),
+            evening_session_datetime: value.evening_session_price_time.map(|x| x.into()),
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use tinkoff_invest_types as tit;
+
+use crate::types;
+
+/// Маржинальные показатели по счёту.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarginAttributes {
+    /// Ликвидная стоимость портфеля.
+    pub liquid_portfolio: Option<types::Money>,
+    /// Начальная маржа.
+    pub starting_margin: Option<types::Money>,
+    /// Минимальная маржа.
+    pub minimal_margin: Option<types::Money>,
+    /// Уровень достаточности средств: отношение ликвидной стоимости портфеля к начальной марже.
+    pub funds_sufficiency_level: Option<types::MoneyValue>,
+    /// Объём недостающих средств.
+    pub amount_of_missing_funds: Option<types::Money>,
+    /// Скорректированная маржа.
+    pub corrected_margin: Option<types::Money>,
+    /// Гарантийное обеспечение, заблокированное под фьючерсы.
+    pub guarantee_for_futures: Option<types::Money>,
+}
+
+impl From<tit::GetMarginAttributesResponse> for MarginAttributes {
+    fn from(value: tit::GetMarginAttributesResponse) -> Self {
+        Self {
+            liquid_portfolio: value.liquid_portfolio.map(|x| x.into()),
+            starting_margin: value.starting_margin.map(|x| x.into()),
+            minimal_margin: value.minimal_margin.map(|x| x.into()),
+            funds_sufficiency_level: value.funds_sufficiency_level.map(|x| x.into()),
+            amount_of_missing_funds: value.amount_of_missing_funds.map(|x| x.into()),
+            corrected_margin: value.corrected_margin.map(|x| x.into()),
+            guarantee_for_futures: value.guarantee_for_futures.map(|x| x.into()),
+        }
+    }
+}
@@ -5,6 +5,10 @@ use crate::{enums, traits, types};
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MarketInstrument {
     pub uid: types::Uid,
+    /// Идентификатор актива. Для фьючерсов и опционов не заполняется.
+    pub asset_uid: Option<types::Uid>,
+    /// Идентификатор позиции.
+    pub position_uid: Option<types::Uid>,
     pub figi: Option<types::Figi>,
     pub isin: Option<types::Isin>,
     pub ticker: types::Ticker,
@@ -20,8 +24,24 @@ pub struct MarketInstrument {
     pub future_asset: Option<String>,
     pub future_asset_size: Option<types::MoneyValue>,
     pub future_expiration_date: Option<types::DateTime>,
+    /// Номинал облигации.
+    pub bond_nominal: Option<types::Money>,
+    /// Количество выплат по купонам в год.
+    pub bond_coupon_quantity_per_year: Option<u32>,
+    /// Дата погашения облигации.
+    pub bond_maturity_date: Option<types::DateTime>,
+    /// Накопленный купонный доход.
+    pub bond_accrued_interest: Option<types::Money>,
+    /// Признак облигации с плавающим купоном.
+    pub bond_floating_coupon: Option<bool>,
     pub option_strike_price: Option<types::MoneyValue>,
     pub option_expiration_date: Option<types::DateTime>,
+    /// Направление опциона.
+    pub option_direction: Option<enums::OptionDirection>,
+    /// Стиль опциона.
+    pub option_style: Option<enums::OptionStyle>,
+    /// Способ исполнения опциона.
+    pub option_settlement_type: Option<enums::OptionSettlementType>,
     pub is_api_trade_available: bool,
     pub is_buy_available: bool,
     pub is_sell_available: bool,
@@ -32,6 +52,8 @@ impl From<tit::Currency> for MarketInstrument {
         let trading_status = value.trading_status().into();
         Self {
             uid: value.uid.as_str().into(),
+            asset_uid: value.asset_uid.parse().ok(),
+            position_uid: value.position_uid.parse().ok(),
             figi: Some(value.figi.into()),
             isin: Some(value.isin.into()),
             ticker: value.ticker.into(),
@@ -47,8 +69,16 @@ impl From<tit::Currency> for MarketInstrument {
             future_asset: None,
             future_asset_size: None,
             future_expiration_date: None,
+            bond_nominal: None,
+            bond_coupon_quantity_per_year: None,
+            bond_maturity_date: None,
+            bond_accrued_interest: None,
+            bond_floating_coupon: None,
             option_strike_price: None,
             option_expiration_date: None,
+            option_direction: None,
+            option_style: None,
+            option_settlement_type: None,
             is_api_trade_available: value.api_trade_available_flag,
             is_buy_available: value.buy_available_flag,
             is_sell_available: value.sell_available_flag,
@@ -79,6 +109,8 @@ impl From<tit::Share> for MarketInstrument {
         // };
         Self {
             uid: value.uid.as_str().into(),
+            asset_uid: value.asset_uid.parse().ok(),
+            position_uid: value.position_uid.parse().ok(),
             figi: Some(value.figi.into()),
             isin: Some(value.isin.into()),
             ticker: value.ticker.into(),
@@ -94,8 +126,16 @@ impl From<tit::Share> for MarketInstrument {
             future_asset: None,
             future_asset_size: None,
             future_expiration_date: None,
+            bond_nominal: None,
+            bond_coupon_quantity_per_year: None,
+            bond_maturity_date: None,
+            bond_accrued_interest: None,
+            bond_floating_coupon: None,
             option_strike_price: None,
             option_expiration_date: None,
+            option_direction: None,
+            option_style: None,
+            option_settlement_type: None,
             is_api_trade_available: value.api_trade_available_flag,
             is_buy_available: value.buy_available_flag,
             is_sell_available: value.sell_available_flag,
@@ -126,6 +166,8 @@ impl From<tit::Future> for MarketInstrument {
         // };
         Self {
             uid: value.uid.as_str().into(),
+            asset_uid: None,
+            position_uid: value.position_uid.parse().ok(),
             figi: Some(value.figi.into()),
             isin: None,
             ticker: value.ticker.into(),
@@ -141,8 +183,94 @@ impl From<tit::Future> for MarketInstrument {
             future_asset: Some(value.basic_asset),
             future_asset_size: value.basic_asset_size.map(|x| x.into()),
             future_expiration_date: value.expiration_date.map(|x| x.into()),
+            bond_nominal: None,
+            bond_coupon_quantity_per_year: None,
+            bond_maturity_date: None,
+            bond_accrued_interest: None,
+            bond_floating_coupon: None,
             option_strike_price: None,
             option_expiration_date: None,
+            option_direction: None,
+            option_style: None,
+            option_settlement_type: None,
+            is_api_trade_available: value.api_trade_available_flag,
+            is_buy_available: value.buy_available_flag,
+            is_sell_available: value.sell_available_flag,
+        }
+    }
+}
+
+impl From<tit::Bond> for MarketInstrument {
+    fn from(value: tit::Bond) -> Self {
+        let trading_status = value.trading_status().into();
+        Self {
+            uid: value.uid.as_str().into(),
+            asset_uid: value.asset_uid.parse().ok(),
+            position_uid: value.position_uid.parse().ok(),
+            figi: Some(value.figi.into()),
+            isin: Some(value.isin.into()),
+            ticker: value.ticker.into(),
+            class_code: value.class_code.into(),
+            instrument_type: enums::InstrumentType::Bond,
+            name: value.name,
+            lot_size: value.lot as u64,
+            currency: value.currency.into(),
+            min_price_increment: value.min_price_increment.map(|x| x.into()),
+            trading_status,
+            risk_rate_long: None,
+            risk_rate_short: None,
+            future_asset: None,
+            future_asset_size: None,
+            future_expiration_date: None,
+            bond_nominal: value.nominal.map(|x| x.into()),
+            bond_coupon_quantity_per_year: Some(value.coupon_quantity_per_year as u32),
+            bond_maturity_date: value.maturity_date.map(|x| x.into()),
+            bond_accrued_interest: value.aci_value.map(|x| x.into()),
+            bond_floating_coupon: Some(value.floating_coupon_flag),
+            option_strike_price: None,
+            option_expiration_date: None,
+            option_direction: None,
+            option_style: None,
+            option_settlement_type: None,
+            is_api_trade_available: value.api_trade_available_flag,
+            is_buy_available: value.buy_available_flag,
+            is_sell_available: value.sell_available_flag,
+        }
+    }
+}
+
+impl From<tit::Etf> for MarketInstrument {
+    fn from(value: tit::Etf) -> Self {
+        let trading_status = value.trading_status().into();
+        Self {
+            uid: value.uid.as_str().into(),
+            asset_uid: value.asset_uid.parse().ok(),
+            position_uid: value.position_uid.parse().ok(),
+            figi: Some(value.figi.into()),
+            isin: Some(value.isin.into()),
+            ticker: value.ticker.into(),
+            class_code: value.class_code.into(),
+            instrument_type: enums::InstrumentType::Etf,
+            name: value.name,
+            lot_size: value.lot as u64,
+            currency: value.currency.into(),
+            min_price_increment: value.min_price_increment.map(|x| x.into()),
+            trading_status,
+            risk_rate_long: None,
+            risk_rate_short: None,
+            future_asset: None,
+            future_asset_size: None,
+            future_expiration_date: None,
+            bond_nominal: None,
+            bond_coupon_quantity_per_year: None,
+            bond_maturity_date: None,
+            bond_accrued_interest: None,
+            bond_floating_coupon: None,
+            option_strike_price: None,
+            option_expiration_date: None,
+            option_direction: None,
+            option_style: None,
+            option_settlement_type: None,
             is_api_trade_available: value.api_trade_available_flag,
             is_buy_available: value.buy_available_flag,
             is_sell_available: value.sell_available_flag,
@@ -153,13 +281,18 @@ impl From<tit::Future> for MarketInstrument {
 impl From<tit::Option> for MarketInstrument {
     fn from(value: tit::Option) -> Self {
         let trading_status = value.trading_status().into();
+        let direction = value.direction().into();
+        let style = value.style().into();
+        let settlement_type = value.settlement_type().into();
         Self {
             uid: value.uid.as_str().into(),
+            asset_uid: None,
+            position_uid: value.position_uid.parse().ok(),
             figi: None,
             isin: None,
             ticker: value.ticker.into(),
             class_code: value.class_code.into(),
-            instrument_type: enums::InstrumentType::Future,
+            instrument_type: enums::InstrumentType::Option,
             name: value.name,
             lot_size: value.lot as u64,
             currency: value.currency.into(),
@@ -170,8 +303,16 @@ impl From<tit::Option> for MarketInstrument {
             future_asset: None,
             future_asset_size: None,
             future_expiration_date: None,
+            bond_nominal: None,
+            bond_coupon_quantity_per_year: None,
+            bond_maturity_date: None,
+            bond_accrued_interest: None,
+            bond_floating_coupon: None,
             option_strike_price: value.strike_price.map(|x| x.into()),
             option_expiration_date: value.expiration_date.map(|x| x.into()),
+            option_direction: Some(direction),
+            option_style: Some(style),
+            option_settlement_type: Some(settlement_type),
             is_api_trade_available: value.api_trade_available_flag,
             is_buy_available: value.buy_available_flag,
             is_sell_available: value.sell_available_flag,
@@ -0,0 +1,35 @@
+use tinkoff_invest_types as tit;
+
+use crate::{enums, types};
+
+/// Обезличенная сделка.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarketTrade {
+    /// Идентификатор инструмента. `None`, если сервер вернул пустой или некорректный UID.
+    pub instrument_uid: Option<types::Uid>,
+    /// Направление сделки.
+    pub direction: enums::OrderDirection,
+    /// Цена за 1 инструмент.
+    pub price: Option<types::MoneyValue>,
+    /// Количество лотов.
+    pub lots: u64,
+    /// Дата и время сделки.
+    pub datetime: Option<types::DateTime>,
+    /// Источник сделки.
+    pub source: enums::TradeSource,
+}
+
+impl From<tit::Trade> for MarketTrade {
+    fn from(value: tit::Trade) -> Self {
+        let direction = value.direction().into();
+        let source = value.trade_source().into();
+        Self {
+            instrument_uid: value.instrument_uid.parse().ok(),
+            direction,
+            price: value.price.map(|x| x.into()),
+            lots: value.quantity as u64,
+            datetime: value.time.map(|x| x.into()),
+            source,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use tinkoff_invest_types as tit;
+
+use crate::{enums, types};
+
+/// Максимальное количество лотов, доступное для покупки и продажи.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaxLots {
+    pub currency: enums::Currency,
+    /// Количество доступной валюты для покупки на собственные деньги.
+    pub buy_money_amount: Option<types::MoneyValue>,
+    pub buy_max_lots: u64,
+    /// Максимальное количество лотов для покупки по рыночной цене на текущий момент.
+    pub buy_max_market_lots: u64,
+    /// Количество доступной валюты для покупки с учётом маржинального кредитования.
+    pub buy_margin_money_amount: Option<types::MoneyValue>,
+    pub buy_margin_max_lots: u64,
+    pub buy_margin_max_market_lots: u64,
+    pub sell_max_lots: u64,
+    /// Максимальное количество лотов для продажи с учётом маржинального кредитования.
+    pub sell_margin_max_lots: u64,
+}
+
+impl From<tit::GetMaxLotsResponse> for MaxLots {
+    fn from(value: tit::GetMaxLotsResponse) -> Self {
+        let buy_limits = value.buy_limits.unwrap_or_default();
+        let buy_margin_limits = value.buy_margin_limits.unwrap_or_default();
+        Self {
+            currency: value.currency.into(),
+            buy_money_amount: buy_limits.buy_money_amount.map(|x| x.into()),
+            buy_max_lots: buy_limits.buy_max_lots as u64,
+            buy_max_market_lots: buy_limits.buy_max_market_lots as u64,
+            buy_margin_money_amount: buy_margin_limits.buy_money_amount.map(|x| x.into()),
+            buy_margin_max_lots: buy_margin_limits.buy_max_lots as u64,
+            buy_margin_max_market_lots: buy_margin_limits.buy_max_market_lots as u64,
+            sell_max_lots: value
+                .sell_limits
+                .map(|x| x.sell_max_lots)
+                .unwrap_or_default() as u64,
+            sell_margin_max_lots: value
+                .sell_margin_limits
+                .map(|x| x.sell_max_lots)
+                .unwrap_or_default() as u64,
+        }
+    }
+}
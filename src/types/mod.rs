@@ -1,35 +1,77 @@
 mod account;
+mod asset;
+mod asset_fundamentals;
+mod bond_event;
+mod broker_report;
 mod candlestick;
 mod class_code_ticker;
+mod coupon;
 mod datetime;
+mod dividend;
+mod favorite_group;
 mod figi;
+mod forecast;
+mod futures_margin;
+mod instrument_short;
 mod isin;
+mod last_price;
+mod margin_attributes;
 mod market_instrument;
+mod market_trade;
+mod max_lots;
 mod money;
 mod operation;
+mod option_chain;
 mod order;
 mod order_id;
+mod order_price;
 mod orderbook;
 mod portfolio;
 mod positions;
+mod stop_order;
 mod ticker;
 mod trade;
+mod trading_schedule;
 mod uid;
+mod user_info;
+mod user_tariff;
+mod withdraw_limits;
 
 pub use account::{Account, AccountId};
+pub use asset::{Asset, AssetInstrument};
+pub use asset_fundamentals::AssetFundamentals;
+pub use bond_event::BondEvent;
+pub use broker_report::{BrokerReportItem, DividendForeignIssuerItem};
 pub use candlestick::Candlestick;
 pub use class_code_ticker::ClassCodeTicker;
+pub use coupon::{AccruedInterest, Coupon};
 pub use datetime::DateTime;
+pub use dividend::Dividend;
+pub use favorite_group::FavoriteGroup;
 pub use figi::Figi;
+pub use forecast::{Forecast, ForecastConsensus, ForecastTarget};
+pub use futures_margin::FuturesMargin;
+pub use instrument_short::{InstrumentShort, InstrumentsFilter};
 pub use isin::Isin;
+pub use last_price::{ClosePrice, LastPrice};
+pub use margin_attributes::MarginAttributes;
 pub use market_instrument::MarketInstrument;
+pub use market_trade::MarketTrade;
+pub use max_lots::MaxLots;
 pub use money::{Money, MoneyValue};
-pub use operation::Operation;
-pub use order::Order;
+pub use operation::{Operation, OperationsFilter};
+pub use option_chain::{OptionChain, OptionChainExpiration, OptionChainStrike};
+pub use order::{Order, OrderFilter, OrderRequest};
 pub use order_id::OrderId;
+pub use order_price::OrderPrice;
 pub use orderbook::{OrderBook, OrderBookOrder};
-pub use portfolio::PortfolioPosition;
+pub use portfolio::{Portfolio, PortfolioPosition, VirtualPortfolioPosition};
 pub use positions::Positions;
+pub use stop_order::{StopOrder, StopOrderRequest};
 pub use ticker::Ticker;
 pub use trade::Trade;
+pub use trading_schedule::{TradingCalendar, TradingDay, TradingSchedule, TradingSession};
 pub use uid::Uid;
+pub use user_info::UserInfo;
+pub use user_tariff::{StreamLimit, UnaryLimit, UserTariff};
+pub use withdraw_limits::{WithdrawLimit, WithdrawLimits};
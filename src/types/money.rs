@@ -122,6 +122,16 @@ impl From<&tit::MoneyValue> for Money {
     }
 }
 
+impl From<Money> for tit::MoneyValue {
+    fn from(value: Money) -> Self {
+        tit::MoneyValue {
+            currency: value.currency.into(),
+            units: value.value.units,
+            nano: value.value.nano,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::types::MoneyValue;
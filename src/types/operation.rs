@@ -1,6 +1,6 @@
 use tinkoff_invest_types as tit;
 
-use crate::{enums, types};
+use crate::{enums, traits, types};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Operation {
@@ -10,6 +10,8 @@ pub struct Operation {
     pub parent_id: Option<String>,
     /// FIGI инструмента операции.
     pub figi: types::Figi,
+    /// UID инструмента операции.
+    pub instrument_uid: Option<types::Uid>,
     /// Количество лотов инструмента операции.
     pub lots: u64,
     /// Неисполненное количество лотов инструмента операции.
@@ -18,6 +20,8 @@ pub struct Operation {
     pub price: Option<types::Money>,
     /// Суммарная стоимость операции.
     pub total: Option<types::Money>,
+    /// Комиссия операции.
+    pub commission: Option<types::Money>,
     /// Валюта операции.
     pub currency: enums::Currency,
     /// Состояние операции.
@@ -43,8 +47,10 @@ impl From<tit::Operation> for Operation {
             id: value.id,
             parent_id,
             figi: value.figi.into(),
+            instrument_uid: value.instrument_uid.parse().ok(),
             currency: value.currency.into(),
             total: value.payment.map(|x| x.into()),
+            commission: None,
             price: value.price.map(|x| x.into()),
             state,
             lots: value.quantity as u64,
@@ -55,3 +61,124 @@ impl From<tit::Operation> for Operation {
         }
     }
 }
+
+impl From<tit::OperationItem> for Operation {
+    fn from(value: tit::OperationItem) -> Self {
+        let parent_id = if !value.parent_operation_id.is_empty() {
+            Some(value.parent_operation_id.clone())
+        } else {
+            None
+        };
+        let currency = value
+            .payment
+            .as_ref()
+            .map(|x| x.currency.clone())
+            .unwrap_or_default()
+            .into();
+        let state = value.state().into();
+        let operation_type = value.r#type().into();
+        Operation {
+            id: value.id,
+            parent_id,
+            figi: value.figi.into(),
+            instrument_uid: value.instrument_uid.parse().ok(),
+            currency,
+            total: value.payment.map(|x| x.into()),
+            commission: value.commission.map(|x| x.into()),
+            price: value.price.map(|x| x.into()),
+            state,
+            lots: value.quantity as u64,
+            lots_rest: value.quantity_rest as u64,
+            operation_type,
+            trades: value
+                .trades_info
+                .map(|x| x.trades.into_iter().map(|x| x.into()).collect())
+                .unwrap_or_default(),
+            datetime: value.date.map(|x| x.into()),
+        }
+    }
+}
+
+/// Фильтр операций для постраничного получения по курсору.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationsFilter {
+    pub instrument_uid: Option<types::Uid>,
+    pub from: Option<types::DateTime>,
+    pub to: Option<types::DateTime>,
+    pub operation_types: Vec<enums::OperationType>,
+    pub state: Option<enums::OperationState>,
+    /// Получать комиссии.
+    pub with_commissions: bool,
+    /// Получать сделки.
+    pub with_trades: bool,
+    /// Количество операций в одном запросе, от 1 до 1000. Значения вне диапазона ограничиваются.
+    pub page_size: u32,
+}
+
+impl OperationsFilter {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            instrument_uid: None,
+            from: None,
+            to: None,
+            operation_types: Vec::new(),
+            state: None,
+            with_commissions: true,
+            with_trades: true,
+            page_size: 100,
+        }
+    }
+
+    #[inline]
+    pub fn set_instrument<T>(&mut self, instrument: T) -> &mut Self
+    where
+        T: traits::ToUid,
+    {
+        self.instrument_uid = Some(instrument.to_uid());
+        self
+    }
+
+    #[inline]
+    pub fn set_period(&mut self, from: types::DateTime, to: types::DateTime) -> &mut Self {
+        self.from = Some(from);
+        self.to = Some(to);
+        self
+    }
+
+    #[inline]
+    pub fn add_operation_type(&mut self, operation_type: enums::OperationType) -> &mut Self {
+        self.operation_types.push(operation_type);
+        self
+    }
+
+    #[inline]
+    pub fn set_state(&mut self, state: enums::OperationState) -> &mut Self {
+        self.state = Some(state);
+        self
+    }
+
+    #[inline]
+    pub fn set_with_commissions(&mut self, value: bool) -> &mut Self {
+        self.with_commissions = value;
+        self
+    }
+
+    #[inline]
+    pub fn set_with_trades(&mut self, value: bool) -> &mut Self {
+        self.with_trades = value;
+        self
+    }
+
+    #[inline]
+    pub fn set_page_size(&mut self, page_size: u32) -> &mut Self {
+        self.page_size = page_size.clamp(1, 1000);
+        self
+    }
+}
+
+impl Default for OperationsFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
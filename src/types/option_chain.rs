@@ -0,0 +1,166 @@
+use crate::{enums, types};
+
+/// Опционная доска по базовому активу.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionChain {
+    /// Идентификатор базового актива.
+    pub basic_asset_uid: types::Uid,
+    /// Серии опционов, отсортированные по дате экспирации.
+    pub expirations: Vec<OptionChainExpiration>,
+}
+
+/// Серия опционов с общей датой экспирации.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionChainExpiration {
+    /// Дата экспирации.
+    pub expiration_date: Option<types::DateTime>,
+    /// Страйки серии, отсортированные по возрастанию цены.
+    pub strikes: Vec<OptionChainStrike>,
+}
+
+/// Пара колл/пут с общей ценой страйка.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionChainStrike {
+    /// Цена страйка.
+    pub strike_price: Option<types::MoneyValue>,
+    /// Опцион на покупку.
+    pub call: Option<types::MarketInstrument>,
+    /// Опцион на продажу.
+    pub put: Option<types::MarketInstrument>,
+}
+
+impl OptionChain {
+    pub fn new(basic_asset_uid: types::Uid, options: Vec<types::MarketInstrument>) -> Self {
+        let mut expirations: Vec<OptionChainExpiration> = Vec::new();
+        for option in options {
+            let expiration_index = match expirations
+                .iter()
+                .position(|x| x.expiration_date == option.option_expiration_date)
+            {
+                Some(index) => index,
+                None => {
+                    expirations.push(OptionChainExpiration {
+                        expiration_date: option.option_expiration_date.clone(),
+                        strikes: Vec::new(),
+                    });
+                    expirations.len() - 1
+                }
+            };
+            let strikes = &mut expirations[expiration_index].strikes;
+            let strike_index = match strikes
+                .iter()
+                .position(|x| x.strike_price == option.option_strike_price)
+            {
+                Some(index) => index,
+                None => {
+                    strikes.push(OptionChainStrike {
+                        strike_price: option.option_strike_price.clone(),
+                        call: None,
+                        put: None,
+                    });
+                    strikes.len() - 1
+                }
+            };
+            let strike = &mut strikes[strike_index];
+            match option.option_direction {
+                Some(enums::OptionDirection::Call) => strike.call = Some(option),
+                Some(enums::OptionDirection::Put) => strike.put = Some(option),
+                _ => {}
+            }
+        }
+        for expiration in expirations.iter_mut() {
+            expiration.strikes.sort_by_key(|x| {
+                x.strike_price
+                    .as_ref()
+                    .map(|price| (price.units, price.nano))
+            });
+        }
+        expirations.sort_by(|a, b| a.expiration_date.cmp(&b.expiration_date));
+        Self {
+            basic_asset_uid,
+            expirations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tinkoff_invest_types as tit;
+
+    use crate::types::{DateTime, MarketInstrument, MoneyValue, OptionChain, Uid};
+
+    fn option(
+        uid: &str,
+        direction: tit::OptionDirection,
+        strike: i64,
+        expiration: i64,
+    ) -> MarketInstrument {
+        let mut option = tit::Option {
+            uid: uid.into(),
+            strike_price: Some(tit::MoneyValue {
+                currency: "rub".into(),
+                units: strike,
+                nano: 0,
+            }),
+            expiration_date: Some(tit::prost_types::Timestamp {
+                seconds: expiration,
+                nanos: 0,
+            }),
+            ..Default::default()
+        };
+        option.set_direction(direction);
+        option.into()
+    }
+
+    #[test]
+    fn test_group_by_expiration_and_strike() {
+        let basic_asset_uid = Uid::from("00000000-0000-0000-0000-000000000000");
+        let options = vec![
+            option(
+                "00000000-0000-0000-0000-000000000001",
+                tit::OptionDirection::Call,
+                110,
+                200,
+            ),
+            option(
+                "00000000-0000-0000-0000-000000000002",
+                tit::OptionDirection::Put,
+                100,
+                100,
+            ),
+            option(
+                "00000000-0000-0000-0000-000000000003",
+                tit::OptionDirection::Call,
+                100,
+                100,
+            ),
+            option(
+                "00000000-0000-0000-0000-000000000004",
+                tit::OptionDirection::Call,
+                90,
+                100,
+            ),
+        ];
+        let chain = OptionChain::new(basic_asset_uid, options);
+        assert_eq!(chain.expirations.len(), 2);
+
+        let first = &chain.expirations[0];
+        assert_eq!(
+            first.expiration_date,
+            Some(DateTime {
+                seconds: 100,
+                nanoseconds: 0
+            })
+        );
+        assert_eq!(first.strikes.len(), 2);
+        assert_eq!(first.strikes[0].strike_price, Some(MoneyValue::from(90)));
+        assert!(first.strikes[0].put.is_none());
+        assert_eq!(first.strikes[1].strike_price, Some(MoneyValue::from(100)));
+        assert!(first.strikes[1].call.is_some());
+        assert!(first.strikes[1].put.is_some());
+
+        let second = &chain.expirations[1];
+        assert_eq!(second.strikes.len(), 1);
+        assert_eq!(second.strikes[0].strike_price, Some(MoneyValue::from(110)));
+    }
+}
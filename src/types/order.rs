@@ -7,6 +7,8 @@ use crate::{enums, traits, types};
 pub struct Order {
     /// Идентификатор.
     pub id: String,
+    /// Идентификатор инструмента. `None`, если сервер вернул пустой или некорректный UID.
+    pub instrument_uid: Option<types::Uid>,
     /// Глобальный идентификатор финансового инструмента.
     pub figi: types::Figi,
     /// Тип.
@@ -37,6 +39,7 @@ impl From<tit::PostOrderResponse> for Order {
         let status = value.execution_report_status().into();
         Order {
             id: value.order_id,
+            instrument_uid: value.instrument_uid.parse().ok(),
             figi,
             kind,
             direction,
@@ -61,6 +64,7 @@ impl From<tit::OrderState> for Order {
         let status = value.execution_report_status().into();
         Order {
             id: value.order_id,
+            instrument_uid: value.instrument_uid.parse().ok(),
             figi,
             kind,
             direction,
@@ -82,3 +86,188 @@ impl traits::ToOrderId for Order {
         self.id.clone().into()
     }
 }
+
+/// Фильтр заявок по инструменту, направлению и статусу исполнения.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrderFilter {
+    pub instrument_uid: Option<types::Uid>,
+    pub direction: Option<enums::OrderDirection>,
+    pub statuses: Vec<enums::OrderStatus>,
+}
+
+impl OrderFilter {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_instrument<T>(&mut self, instrument: T) -> &mut Self
+    where
+        T: traits::ToUid,
+    {
+        self.instrument_uid = Some(instrument.to_uid());
+        self
+    }
+
+    #[inline]
+    pub fn set_direction(&mut self, direction: enums::OrderDirection) -> &mut Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    #[inline]
+    pub fn add_status(&mut self, status: enums::OrderStatus) -> &mut Self {
+        self.statuses.push(status);
+        self
+    }
+
+    pub fn matches(&self, order: &Order) -> bool {
+        if let Some(instrument_uid) = self.instrument_uid.as_ref()
+            && order.instrument_uid.as_ref() != Some(instrument_uid)
+        {
+            return false;
+        }
+        if let Some(direction) = self.direction.as_ref()
+            && order.direction != *direction
+        {
+            return false;
+        }
+        self.statuses.is_empty() || self.statuses.contains(&order.status)
+    }
+
+    pub fn apply(&self, orders: Vec<Order>) -> Vec<Order> {
+        orders.into_iter().filter(|x| self.matches(x)).collect()
+    }
+}
+
+/// Параметры новой заявки.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderRequest {
+    /// Тип.
+    pub kind: enums::OrderKind,
+    pub direction: enums::OrderDirection,
+    /// Кол-во лотов.
+    pub lots: u64,
+    /// Цена за 1 инструмент (только для лимитной заявки).
+    pub price: Option<types::MoneyValue>,
+    /// Время жизни заявки.
+    pub time_in_force: enums::TimeInForce,
+    /// Тип цены.
+    pub price_type: enums::PriceType,
+}
+
+impl OrderRequest {
+    #[inline]
+    pub fn new(kind: enums::OrderKind, direction: enums::OrderDirection, lots: u64) -> Self {
+        Self {
+            kind,
+            direction,
+            lots,
+            price: None,
+            time_in_force: enums::TimeInForce::Unspecified,
+            price_type: enums::PriceType::Unspecified,
+        }
+    }
+
+    #[inline]
+    pub fn limit(direction: enums::OrderDirection, lots: u64, price: types::MoneyValue) -> Self {
+        let mut order = Self::new(enums::OrderKind::Limit, direction, lots);
+        order.price = Some(price);
+        order
+    }
+
+    #[inline]
+    pub fn market(direction: enums::OrderDirection, lots: u64) -> Self {
+        Self::new(enums::OrderKind::Market, direction, lots)
+    }
+
+    #[inline]
+    pub fn best_price(direction: enums::OrderDirection, lots: u64) -> Self {
+        Self::new(enums::OrderKind::BestPrice, direction, lots)
+    }
+
+    #[inline]
+    pub fn set_price(&mut self, price: types::MoneyValue) -> &mut Self {
+        self.price = Some(price);
+        self
+    }
+
+    #[inline]
+    pub fn set_time_in_force(&mut self, time_in_force: enums::TimeInForce) -> &mut Self {
+        self.time_in_force = time_in_force;
+        self
+    }
+
+    #[inline]
+    pub fn set_price_type(&mut self, price_type: enums::PriceType) -> &mut Self {
+        self.price_type = price_type;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tinkoff_invest_types as tit;
+
+    use crate::enums::{OrderDirection, OrderStatus};
+    use crate::types::{Order, OrderFilter, Uid};
+
+    fn order(
+        instrument_uid: &str,
+        direction: tit::OrderDirection,
+        status: tit::OrderExecutionReportStatus,
+    ) -> Order {
+        let mut order_state = tit::OrderState {
+            instrument_uid: instrument_uid.into(),
+            ..Default::default()
+        };
+        order_state.set_direction(direction);
+        order_state.set_execution_report_status(status);
+        order_state.into()
+    }
+
+    #[test]
+    fn test_order_filter() {
+        let sber = "e6123145-9665-43e0-8413-cd61b8aa9b13";
+        let gazp = "962e2a95-02a9-4171-abd7-aa198dbe643a";
+        let orders = vec![
+            order(
+                sber,
+                tit::OrderDirection::Buy,
+                tit::OrderExecutionReportStatus::ExecutionReportStatusNew,
+            ),
+            order(
+                sber,
+                tit::OrderDirection::Sell,
+                tit::OrderExecutionReportStatus::ExecutionReportStatusPartiallyfill,
+            ),
+            order(
+                gazp,
+                tit::OrderDirection::Buy,
+                tit::OrderExecutionReportStatus::ExecutionReportStatusFill,
+            ),
+        ];
+
+        assert_eq!(OrderFilter::new().apply(orders.clone()).len(), 3);
+
+        let mut filter = OrderFilter::new();
+        filter.set_instrument(Uid::from(sber));
+        assert_eq!(filter.apply(orders.clone()).len(), 2);
+
+        filter.set_direction(OrderDirection::Sell);
+        assert_eq!(filter.apply(orders.clone()).len(), 1);
+
+        let mut filter = OrderFilter::new();
+        filter
+            .add_status(OrderStatus::New)
+            .add_status(OrderStatus::PartiallyFill);
+        let active = filter.apply(orders);
+        assert_eq!(active.len(), 2);
+        assert!(
+            active
+                .iter()
+                .all(|x| x.instrument_uid == Some(Uid::from(sber)))
+        );
+    }
+}
@@ -1,4 +1,6 @@
-#[derive(Debug, PartialEq, Eq)]
+use crate::traits;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OrderId(String);
 
 impl From<OrderId> for String {
@@ -12,3 +14,9 @@ impl From<String> for OrderId {
         Self(value)
     }
 }
+
+impl traits::ToOrderId for OrderId {
+    fn to_order_id(&self) -> OrderId {
+        self.clone()
+    }
+}
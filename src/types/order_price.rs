@@ -0,0 +1,56 @@
+use tinkoff_invest_types::{self as tit, get_order_price_response::InstrumentExtra};
+
+use crate::types;
+
+/// Предварительная стоимость заявки.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderPrice {
+    /// Итоговая стоимость заявки.
+    pub total_order_amount: Option<types::Money>,
+    /// Стоимость заявки без комиссий, НКД и гарантийного обеспечения.
+    pub initial_order_amount: Option<types::Money>,
+    pub lots_requested: u64,
+    /// Общая комиссия.
+    pub executed_commission: Option<types::Money>,
+    /// Общая комиссия в рублях.
+    pub executed_commission_rub: Option<types::Money>,
+    /// Сервисная комиссия.
+    pub service_commission: Option<types::Money>,
+    /// Комиссия за проведение сделки.
+    pub deal_commission: Option<types::Money>,
+    /// НКД, для облигаций.
+    pub bond_accrued_interest: Option<types::Money>,
+    /// Курс конвертации для замещающих облигаций.
+    pub bond_nominal_conversion_rate: Option<types::MoneyValue>,
+    /// Гарантийное обеспечение, для фьючерсов.
+    pub future_initial_margin: Option<types::Money>,
+}
+
+impl From<tit::GetOrderPriceResponse> for OrderPrice {
+    fn from(value: tit::GetOrderPriceResponse) -> Self {
+        let mut order_price = Self {
+            total_order_amount: value.total_order_amount.map(|x| x.into()),
+            initial_order_amount: value.initial_order_amount.map(|x| x.into()),
+            lots_requested: value.lots_requested as u64,
+            executed_commission: value.executed_commission.map(|x| x.into()),
+            executed_commission_rub: value.executed_commission_rub.map(|x| x.into()),
+            service_commission: value.service_commission.map(|x| x.into()),
+            deal_commission: value.deal_commission.map(|x| x.into()),
+            bond_accrued_interest: None,
+            bond_nominal_conversion_rate: None,
+            future_initial_margin: None,
+        };
+        match value.instrument_extra {
+            Some(InstrumentExtra::ExtraBond(extra)) => {
+                order_price.bond_accrued_interest = extra.aci_value.map(|x| x.into());
+                order_price.bond_nominal_conversion_rate =
+                    extra.nominal_conversion_rate.map(|x| x.into());
+            }
+            Some(InstrumentExtra::ExtraFuture(extra)) => {
+                order_price.future_initial_margin = extra.initial_margin.map(|x| x.into());
+            }
+            None => {}
+        }
+        order_price
+    }
+}
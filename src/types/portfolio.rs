@@ -2,21 +2,165 @@ use tinkoff_invest_types as tit;
 
 use crate::types;
 
+/// Портфель по счёту.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Portfolio {
+    /// Идентификатор счёта.
+    pub account_id: types::AccountId,
+    /// Общая стоимость акций.
+    pub total_amount_shares: Option<types::Money>,
+    /// Общая стоимость облигаций.
+    pub total_amount_bonds: Option<types::Money>,
+    /// Общая стоимость фондов.
+    pub total_amount_etf: Option<types::Money>,
+    /// Общая стоимость валют.
+    pub total_amount_currencies: Option<types::Money>,
+    /// Общая стоимость фьючерсов.
+    pub total_amount_futures: Option<types::Money>,
+    /// Общая стоимость опционов.
+    pub total_amount_options: Option<types::Money>,
+    /// Общая стоимость структурных нот.
+    pub total_amount_structured_products: Option<types::Money>,
+    /// Общая стоимость портфеля.
+    pub total_amount_portfolio: Option<types::Money>,
+    /// Текущая относительная доходность портфеля в %.
+    pub expected_yield: Option<types::MoneyValue>,
+    /// Доходность портфеля за день.
+    pub daily_yield: Option<types::Money>,
+    /// Относительная доходность портфеля за день в %.
+    pub daily_yield_relative: Option<types::MoneyValue>,
+    /// Позиции портфеля.
+    pub positions: Vec<PortfolioPosition>,
+    /// Виртуальные позиции портфеля.
+    pub virtual_positions: Vec<VirtualPortfolioPosition>,
+}
+
+impl From<tit::PortfolioResponse> for Portfolio {
+    fn from(value: tit::PortfolioResponse) -> Self {
+        Self {
+            account_id: value.account_id.into(),
+            total_amount_shares: value.total_amount_shares.map(|x| x.into()),
+            total_amount_bonds: value.total_amount_bonds.map(|x| x.into()),
+            total_amount_etf: value.total_amount_etf.map(|x| x.into()),
+            total_amount_currencies: value.total_amount_currencies.map(|x| x.into()),
+            total_amount_futures: value.total_amount_futures.map(|x| x.into()),
+            total_amount_options: value.total_amount_options.map(|x| x.into()),
+            total_amount_structured_products: value.total_amount_sp.map(|x| x.into()),
+            total_amount_portfolio: value.total_amount_portfolio.map(|x| x.into()),
+            expected_yield: value.expected_yield.map(|x| x.into()),
+            daily_yield: value.daily_yield.map(|x| x.into()),
+            daily_yield_relative: value.daily_yield_relative.map(|x| x.into()),
+            positions: value.positions.iter().map(|x| x.into()).collect(),
+            virtual_positions: value.virtual_positions.iter().map(|x| x.into()).collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PortfolioPosition {
-    /// Идентификатор инструмента
-    pub instrument_uid: types::Uid,
+    /// Идентификатор инструмента. `None`, если сервер вернул пустой или некорректный UID.
+    pub instrument_uid: Option<types::Uid>,
+    /// Идентификатор позиции.
+    pub position_uid: Option<types::Uid>,
+    pub figi: types::Figi,
+    pub ticker: types::Ticker,
+    pub class_code: String,
+    /// Тип инструмента.
+    pub instrument_type: String,
     /// Количество инструмента в портфеле в штуках.
     pub quantity_total: Option<types::MoneyValue>,
     pub quantity_blocked: Option<types::MoneyValue>,
+    /// Заблокировано на бирже.
+    pub blocked: bool,
+    /// Средневзвешенная цена позиции.
+    pub average_price: Option<types::Money>,
+    /// Средняя цена позиции по методу FIFO.
+    pub average_price_fifo: Option<types::Money>,
+    /// Текущая цена за 1 инструмент.
+    pub current_price: Option<types::Money>,
+    /// Текущая доходность позиции.
+    pub expected_yield: Option<types::MoneyValue>,
+    /// Текущая доходность позиции по методу FIFO.
+    pub expected_yield_fifo: Option<types::MoneyValue>,
+    /// Доходность позиции за день.
+    pub daily_yield: Option<types::Money>,
+    /// Текущий НКД.
+    pub accrued_coupon: Option<types::Money>,
+    /// Вариационная маржа.
+    pub variation_margin: Option<types::Money>,
 }
 
 impl From<&tit::PortfolioPosition> for PortfolioPosition {
     fn from(value: &tit::PortfolioPosition) -> Self {
         Self {
-            instrument_uid: value.instrument_uid.as_str().into(),
+            instrument_uid: value.instrument_uid.parse().ok(),
+            position_uid: value.position_uid.parse().ok(),
+            figi: value.figi.clone().into(),
+            ticker: value.ticker.clone().into(),
+            class_code: value.class_code.clone(),
+            instrument_type: value.instrument_type.clone(),
             quantity_total: value.quantity.map(|x| x.into()),
             quantity_blocked: value.blocked_lots.map(|x| x.into()),
+            blocked: value.blocked,
+            average_price: value.average_position_price.clone().map(|x| x.into()),
+            average_price_fifo: value.average_position_price_fifo.clone().map(|x| x.into()),
+            current_price: value.current_price.clone().map(|x| x.into()),
+            expected_yield: value.expected_yield.map(|x| x.into()),
+            expected_yield_fifo: value.expected_yield_fifo.map(|x| x.into()),
+            daily_yield: value.daily_yield.clone().map(|x| x.into()),
+            accrued_coupon: value.current_nkd.clone().map(|x| x.into()),
+            variation_margin: value.var_margin.clone().map(|x| x.into()),
+        }
+    }
+}
+
+/// Виртуальная позиция портфеля.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VirtualPortfolioPosition {
+    /// Идентификатор инструмента. `None`, если сервер вернул пустой или некорректный UID.
+    pub instrument_uid: Option<types::Uid>,
+    /// Идентификатор позиции.
+    pub position_uid: Option<types::Uid>,
+    pub figi: types::Figi,
+    pub ticker: types::Ticker,
+    pub class_code: String,
+    /// Тип инструмента.
+    pub instrument_type: String,
+    /// Количество инструмента в портфеле в штуках.
+    pub quantity: Option<types::MoneyValue>,
+    /// Средневзвешенная цена позиции.
+    pub average_price: Option<types::Money>,
+    /// Средняя цена позиции по методу FIFO.
+    pub average_price_fifo: Option<types::Money>,
+    /// Текущая цена за 1 инструмент.
+    pub current_price: Option<types::Money>,
+    /// Текущая доходность позиции.
+    pub expected_yield: Option<types::MoneyValue>,
+    /// Текущая доходность позиции по методу FIFO.
+    pub expected_yield_fifo: Option<types::MoneyValue>,
+    /// Доходность позиции за день.
+    pub daily_yield: Option<types::Money>,
+    /// Дата, до которой нужно продать виртуальные бумаги.
+    pub expire_date: Option<types::DateTime>,
+}
+
+impl From<&tit::VirtualPortfolioPosition> for VirtualPortfolioPosition {
+    fn from(value: &tit::VirtualPortfolioPosition) -> Self {
+        Self {
+            instrument_uid: value.instrument_uid.parse().ok(),
+            position_uid: value.position_uid.parse().ok(),
+            figi: value.figi.clone().into(),
+            ticker: value.ticker.clone().into(),
+            class_code: value.class_code.clone(),
+            instrument_type: value.instrument_type.clone(),
+            quantity: value.quantity.map(|x| x.into()),
+            average_price: value.average_position_price.clone().map(|x| x.into()),
+            average_price_fifo: value.average_position_price_fifo.clone().map(|x| x.into()),
+            current_price: value.current_price.clone().map(|x| x.into()),
+            expected_yield: value.expected_yield.map(|x| x.into()),
+            expected_yield_fifo: value.expected_yield_fifo.map(|x| x.into()),
+            daily_yield: value.daily_yield.clone().map(|x| x.into()),
+            expire_date: value.expire_date.map(|x| x.into()),
         }
     }
 }
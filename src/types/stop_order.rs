@@ -0,0 +1,114 @@
+use tinkoff_invest_types as tit;
+
+use crate::types::OrderId;
+use crate::{enums, traits, types};
+
+/// Стоп-заявка.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StopOrder {
+    /// Идентификатор.
+    pub id: String,
+    /// Идентификатор инструмента. `None`, если сервер вернул пустой или некорректный UID.
+    pub instrument_uid: Option<types::Uid>,
+    /// Глобальный идентификатор финансового инструмента.
+    pub figi: types::Figi,
+    /// Тип.
+    pub kind: enums::StopOrderKind,
+    pub direction: enums::OrderDirection,
+    /// Кол-во запрошенных лотов.
+    pub lots_requested: u64,
+    /// Валюта.
+    pub currency: enums::Currency,
+    /// Цена активации.
+    pub stop_price: Option<types::Money>,
+    /// Цена лимитной заявки, выставляемой после активации.
+    pub price: Option<types::Money>,
+    /// Статус.
+    pub status: enums::StopOrderStatus,
+    /// Дата и время выставления.
+    pub created_at: Option<types::DateTime>,
+    /// Дата и время активации.
+    pub activated_at: Option<types::DateTime>,
+    /// Дата и время окончания действия.
+    pub expires_at: Option<types::DateTime>,
+}
+
+impl From<tit::StopOrder> for StopOrder {
+    fn from(value: tit::StopOrder) -> Self {
+        let kind = value.order_type().into();
+        let direction = value.direction().into();
+        let status = value.status().into();
+        StopOrder {
+            id: value.stop_order_id,
+            instrument_uid: value.instrument_uid.parse().ok(),
+            figi: value.figi.into(),
+            kind,
+            direction,
+            lots_requested: value.lots_requested as u64,
+            currency: value.currency.into(),
+            stop_price: value.stop_price.map(|x| x.into()),
+            price: value.price.map(|x| x.into()),
+            status,
+            created_at: value.create_date.map(|x| x.into()),
+            activated_at: value.activation_date_time.map(|x| x.into()),
+            expires_at: value.expiration_time.map(|x| x.into()),
+        }
+    }
+}
+
+impl traits::ToOrderId for StopOrder {
+    fn to_order_id(&self) -> OrderId {
+        self.id.clone().into()
+    }
+}
+
+/// Параметры новой стоп-заявки.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StopOrderRequest {
+    /// Тип.
+    pub kind: enums::StopOrderKind,
+    pub direction: enums::OrderDirection,
+    /// Кол-во лотов.
+    pub lots: u64,
+    /// Цена активации.
+    pub stop_price: types::MoneyValue,
+    /// Цена лимитной заявки, выставляемой после активации.
+    pub price: Option<types::MoneyValue>,
+    /// Тип экспирации.
+    pub expiration_type: enums::StopOrderExpirationType,
+    /// Дата и время окончания действия.
+    pub expire_date: Option<types::DateTime>,
+}
+
+impl StopOrderRequest {
+    #[inline]
+    pub fn new(
+        kind: enums::StopOrderKind,
+        direction: enums::OrderDirection,
+        lots: u64,
+        stop_price: types::MoneyValue,
+    ) -> Self {
+        Self {
+            kind,
+            direction,
+            lots,
+            stop_price,
+            price: None,
+            expiration_type: enums::StopOrderExpirationType::GoodTillCancel,
+            expire_date: None,
+        }
+    }
+
+    #[inline]
+    pub fn set_price(&mut self, price: types::MoneyValue) -> &mut Self {
+        self.price = Some(price);
+        self
+    }
+
+    #[inline]
+    pub fn set_expire_date(&mut self, expire_date: types::DateTime) -> &mut Self {
+        self.expiration_type = enums::StopOrderExpirationType::GoodTillDate;
+        self.expire_date = Some(expire_date);
+        self
+    }
+}
@@ -25,3 +25,14 @@ impl From<tit::OperationTrade> for Trade {
         }
     }
 }
+
+impl From<tit::OperationItemTrade> for Trade {
+    fn from(value: tit::OperationItemTrade) -> Self {
+        Trade {
+            id: value.num,
+            lots: value.quantity as u64,
+            price: value.price.map(|x| x.into()),
+            datetime: value.date.map(|x| x.into()),
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+use tinkoff_invest_types as tit;
+
+use crate::{enums, types};
+
+/// Расписание торгов торговой площадки.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradingSchedule {
+    /// Наименование торговой площадки, например `MOEX` или `MOEX_PLUS`.
+    pub exchange: String,
+    pub days: Vec<TradingDay>,
+}
+
+/// Торговый или неторговый день.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradingDay {
+    pub date: Option<types::DateTime>,
+    pub is_trading_day: bool,
+    /// Сессии и перерывы дня, упорядоченные по времени начала.
+    pub sessions: Vec<TradingSession>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradingSession {
+    pub kind: enums::TradingSessionKind,
+    pub start: types::DateTime,
+    pub end: types::DateTime,
+}
+
+impl TradingSession {
+    #[inline]
+    pub fn contains(&self, datetime: &types::DateTime) -> bool {
+        self.start <= *datetime && *datetime < self.end
+    }
+}
+
+impl TradingSchedule {
+    #[inline]
+    fn sessions(&self) -> impl Iterator<Item = &TradingSession> {
+        self.days
+            .iter()
+            .filter(|x| x.is_trading_day)
+            .flat_map(|x| x.sessions.iter())
+    }
+
+    /// Сессия или перерыв в момент `datetime`. Клиринг имеет приоритет над сессией,
+    /// в которую он попадает, из вложенных сессий выбирается самая короткая.
+    pub fn session_at(&self, datetime: &types::DateTime) -> Option<&TradingSession> {
+        let sessions: Vec<&TradingSession> =
+            self.sessions().filter(|x| x.contains(datetime)).collect();
+        if let Some(clearing) = sessions.iter().find(|x| !x.kind.is_trading()) {
+            return Some(clearing);
+        }
+        sessions
+            .into_iter()
+            .min_by_key(|x| x.end.seconds - x.start.seconds)
+    }
+
+    /// Идут ли торги в момент `datetime`.
+    #[inline]
+    pub fn is_open(&self, datetime: &types::DateTime) -> bool {
+        self.session_at(datetime)
+            .is_some_and(|x| x.kind.is_trading())
+    }
+
+    /// Ближайший момент, начиная с `datetime`, в который идут торги.
+    pub fn next_open(&self, datetime: &types::DateTime) -> Option<types::DateTime> {
+        if self.is_open(datetime) {
+            return Some(datetime.clone());
+        }
+        let mut candidates: Vec<&types::DateTime> = self
+            .sessions()
+            .flat_map(|x| {
+                if x.kind.is_trading() {
+                    [Some(&x.start), None]
+                } else {
+                    [None, Some(&x.end)]
+                }
+            })
+            .flatten()
+            .filter(|x| *x > datetime)
+            .collect();
+        candidates.sort();
+        candidates.into_iter().find(|x| self.is_open(x)).cloned()
+    }
+}
+
+#[inline]
+fn session(
+    kind: enums::TradingSessionKind,
+    start: Option<tit::prost_types::Timestamp>,
+    end: Option<tit::prost_types::Timestamp>,
+) -> Option<TradingSession> {
+    let start: types::DateTime = start?.into();
+    let end: types::DateTime = end?.into();
+    if start < end {
+        Some(TradingSession { kind, start, end })
+    } else {
+        None
+    }
+}
+
+impl From<tit::TradingDay> for TradingDay {
+    fn from(value: tit::TradingDay) -> Self {
+        let mut sessions: Vec<TradingSession> = [
+            session(
+                enums::TradingSessionKind::Premarket,
+                value.premarket_start_time,
+                value.premarket_end_time,
+            ),
+            session(
+                enums::TradingSessionKind::OpeningAuction,
+                value.opening_auction_start_time,
+                value.opening_auction_end_time,
+            ),
+            session(
+                enums::TradingSessionKind::Main,
+                value.start_time,
+                value.end_time,
+            ),
+            session(
+                enums::TradingSessionKind::ClosingAuction,
+                value.closing_auction_start_time,
+                value.closing_auction_end_time,
+            ),
+            session(
+                enums::TradingSessionKind::Clearing,
+                value.clearing_start_time,
+                value.clearing_end_time,
+            ),
+            session(
+                enums::TradingSessionKind::EveningOpeningAuction,
+                value.evening_opening_auction_start_time,
+                value.evening_start_time,
+            ),
+            session(
+                enums::TradingSessionKind::Evening,
+                value.evening_start_time,
+                value.evening_end_time,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        sessions.sort_by(|a, b| a.start.cmp(&b.start));
+        Self {
+            date: value.date.map(|x| x.into()),
+            is_trading_day: value.is_trading_day,
+            sessions,
+        }
+    }
+}
+
+impl From<tit::TradingSchedule> for TradingSchedule {
+    fn from(value: tit::TradingSchedule) -> Self {
+        Self {
+            exchange: value.exchange,
+            days: value.days.into_iter().map(|x| x.into()).collect(),
+        }
+    }
+}
+
+/// Торговый календарь по нескольким торговым площадкам.
+#[derive(Debug, Clone, Default)]
+pub struct TradingCalendar {
+    schedules: HashMap<String, TradingSchedule>,
+}
+
+impl TradingCalendar {
+    #[inline]
+    pub fn new(schedules: Vec<TradingSchedule>) -> Self {
+        Self {
+            schedules: schedules
+                .into_iter()
+                .map(|x| (x.exchange.to_uppercase(), x))
+                .collect(),
+        }
+    }
+
+    #[inline]
+    pub fn schedule(&self, exchange: &enums::Exchange) -> Option<&TradingSchedule> {
+        self.schedules
+            .get(&String::from(exchange.clone()).to_uppercase())
+    }
+
+    #[inline]
+    pub fn schedule_by_class_code(
+        &self,
+        class_code: &enums::ClassCode,
+    ) -> Option<&TradingSchedule> {
+        self.schedule(&class_code.exchange()?)
+    }
+
+    #[inline]
+    pub fn is_open(&self, exchange: &enums::Exchange, datetime: &types::DateTime) -> bool {
+        self.schedule(exchange).is_some_and(|x| x.is_open(datetime))
+    }
+
+    #[inline]
+    pub fn next_open(
+        &self,
+        exchange: &enums::Exchange,
+        datetime: &types::DateTime,
+    ) -> Option<types::DateTime> {
+        self.schedule(exchange)?.next_open(datetime)
+    }
+
+    #[inline]
+    pub fn session_at(
+        &self,
+        exchange: &enums::Exchange,
+        datetime: &types::DateTime,
+    ) -> Option<&TradingSession> {
+        self.schedule(exchange)?.session_at(datetime)
+    }
+}
+
+impl From<Vec<TradingSchedule>> for TradingCalendar {
+    fn from(value: Vec<TradingSchedule>) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tinkoff_invest_types as tit;
+
+    use crate::enums::{ClassCode, Exchange, TradingSessionKind};
+    use crate::types::{DateTime, TradingCalendar, TradingSchedule};
+
+    fn timestamp(seconds: i64) -> Option<tit::prost_types::Timestamp> {
+        Some(tit::prost_types::Timestamp { seconds, nanos: 0 })
+    }
+
+    fn datetime(seconds: i64) -> DateTime {
+        DateTime {
+            seconds,
+            nanoseconds: 0,
+        }
+    }
+
+    #[test]
+    fn test_trading_calendar() {
+        let day = tit::TradingDay {
+            is_trading_day: true,
+            start_time: timestamp(1_000),
+            end_time: timestamp(2_000),
+            clearing_start_time: timestamp(1_500),
+            clearing_end_time: timestamp(1_600),
+            evening_start_time: timestamp(2_500),
+            evening_end_time: timestamp(3_000),
+            ..Default::default()
+        };
+        let schedule = TradingSchedule::from(tit::TradingSchedule {
+            exchange: "MOEX".into(),
+            days: vec![day],
+        });
+        let calendar = TradingCalendar::from(vec![schedule]);
+        let moex = Exchange::MOEX;
+
+        assert!(!calendar.is_open(&moex, &datetime(999)));
+        assert!(calendar.is_open(&moex, &datetime(1_000)));
+        assert!(!calendar.is_open(&moex, &datetime(1_550)));
+        assert_eq!(
+            calendar.session_at(&moex, &datetime(1_550)).unwrap().kind,
+            TradingSessionKind::Clearing
+        );
+        assert_eq!(
+            calendar.session_at(&moex, &datetime(2_600)).unwrap().kind,
+            TradingSessionKind::Evening
+        );
+        assert_eq!(
+            calendar.next_open(&moex, &datetime(0)),
+            Some(datetime(1_000))
+        );
+        assert_eq!(
+            calendar.next_open(&moex, &datetime(1_550)),
+            Some(datetime(1_600))
+        );
+        assert_eq!(
+            calendar.next_open(&moex, &datetime(2_100)),
+            Some(datetime(2_500))
+        );
+        assert_eq!(calendar.next_open(&moex, &datetime(3_000)), None);
+        assert!(!calendar.is_open(&Exchange::SPB, &datetime(1_000)));
+        assert!(
+            calendar
+                .schedule_by_class_code(&ClassCode::TQBR)
+                .is_some_and(|x| x.is_open(&datetime(1_000)))
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use std::str::FromStr;
 use uuid::Uuid;
 
 use crate::traits;
@@ -11,6 +12,15 @@ impl From<&str> for Uid {
     }
 }
 
+/// Разбор без паники, в отличие от `From<&str>`.
+impl FromStr for Uid {
+    type Err = uuid::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Uid(Uuid::parse_str(value)?))
+    }
+}
+
 impl From<Uid> for String {
     fn from(value: Uid) -> Self {
         value.0.to_string()
@@ -28,3 +38,19 @@ impl traits::ToUidRef for &Uid {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Uid;
+
+    #[test]
+    fn test_from_str() {
+        assert!(
+            "e6123145-9665-43e0-8413-cd61b8aa9b13"
+                .parse::<Uid>()
+                .is_ok()
+        );
+        assert!("".parse::<Uid>().is_err());
+        assert!("BBG004730N88".parse::<Uid>().is_err());
+    }
+}
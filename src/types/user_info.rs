@@ -0,0 +1,39 @@
+use tinkoff_invest_types as tit;
+
+/// Информация о пользователе.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserInfo {
+    /// Идентификатор пользователя.
+    pub user_id: String,
+    /// Премиум клиент.
+    pub premium: bool,
+    /// Квалифицированный инвестор.
+    pub qualified: bool,
+    /// Инструменты и возможности, доступные после тестирования.
+    pub qualified_for_work_with: Vec<String>,
+    /// Наименование тарифа.
+    pub tariff: String,
+    /// Категория риска.
+    pub risk_level_code: String,
+}
+
+impl UserInfo {
+    /// Доступен ли пользователю инструмент или возможность, требующие тестирования.
+    #[inline]
+    pub fn is_qualified_for(&self, value: &str) -> bool {
+        self.qualified || self.qualified_for_work_with.iter().any(|x| x == value)
+    }
+}
+
+impl From<tit::GetInfoResponse> for UserInfo {
+    fn from(value: tit::GetInfoResponse) -> Self {
+        Self {
+            user_id: value.user_id,
+            premium: value.prem_status,
+            qualified: value.qual_status,
+            qualified_for_work_with: value.qualified_for_work_with,
+            tariff: value.tariff,
+            risk_level_code: value.risk_level_code,
+        }
+    }
+}
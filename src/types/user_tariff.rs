@@ -0,0 +1,76 @@
+use tinkoff_invest_types as tit;
+
+/// Лимиты пользователя.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserTariff {
+    pub unary_limits: Vec<UnaryLimit>,
+    pub stream_limits: Vec<StreamLimit>,
+}
+
+impl UserTariff {
+    /// Лимит для unary-метода, например
+    /// `tinkoff.public.invest.api.contract.v1.MarketDataService/GetCandles`.
+    pub fn unary_limit(&self, method: &str) -> Option<&UnaryLimit> {
+        self.unary_limits
+            .iter()
+            .find(|x| x.methods.iter().any(|x| x == method))
+    }
+
+    /// Лимит для stream-метода.
+    pub fn stream_limit(&self, stream: &str) -> Option<&StreamLimit> {
+        self.stream_limits
+            .iter()
+            .find(|x| x.streams.iter().any(|x| x == stream))
+    }
+}
+
+impl From<tit::GetUserTariffResponse> for UserTariff {
+    fn from(value: tit::GetUserTariffResponse) -> Self {
+        Self {
+            unary_limits: value.unary_limits.into_iter().map(|x| x.into()).collect(),
+            stream_limits: value.stream_limits.into_iter().map(|x| x.into()).collect(),
+        }
+    }
+}
+
+/// Лимит unary-методов.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnaryLimit {
+    /// Количество запросов в минуту.
+    pub limit_per_minute: u32,
+    /// Количество запросов в секунду.
+    pub limit_per_second: Option<u32>,
+    /// Названия методов.
+    pub methods: Vec<String>,
+}
+
+impl From<tit::UnaryLimit> for UnaryLimit {
+    fn from(value: tit::UnaryLimit) -> Self {
+        Self {
+            limit_per_minute: value.limit_per_minute.max(0) as u32,
+            limit_per_second: value.limit_per_second.map(|x| x.max(0) as u32),
+            methods: value.methods,
+        }
+    }
+}
+
+/// Лимит stream-соединений.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamLimit {
+    /// Максимальное количество соединений.
+    pub limit: u32,
+    /// Текущее количество открытых соединений.
+    pub open: u32,
+    /// Названия stream-методов.
+    pub streams: Vec<String>,
+}
+
+impl From<tit::StreamLimit> for StreamLimit {
+    fn from(value: tit::StreamLimit) -> Self {
+        Self {
+            limit: value.limit.max(0) as u32,
+            open: value.open.max(0) as u32,
+            streams: value.streams,
+        }
+    }
+}
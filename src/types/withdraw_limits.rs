@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+use tinkoff_invest_types as tit;
+
+use crate::{enums, types};
+
+/// Доступный для вывода остаток по счёту.
+#[derive(Debug, Clone)]
+pub struct WithdrawLimits {
+    pub money: HashMap<enums::Currency, WithdrawLimit>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawLimit {
+    /// Доступно для вывода.
+    pub money: types::MoneyValue,
+    /// Заблокировано.
+    pub blocked: types::MoneyValue,
+    /// Заблокировано под гарантийное обеспечение фьючерсов.
+    pub blocked_guarantee: types::MoneyValue,
+}
+
+impl Default for WithdrawLimit {
+    fn default() -> Self {
+        Self {
+            money: types::MoneyValue::from(0),
+            blocked: types::MoneyValue::from(0),
+            blocked_guarantee: types::MoneyValue::from(0),
+        }
+    }
+}
+
+impl From<tit::WithdrawLimitsResponse> for WithdrawLimits {
+    fn from(value: tit::WithdrawLimitsResponse) -> Self {
+        let mut money: HashMap<enums::Currency, WithdrawLimit> = HashMap::new();
+        for x in value.money {
+            let x = types::Money::from(x);
+            money.entry(x.currency).or_default().money = x.value;
+        }
+        for x in value.blocked {
+            let x = types::Money::from(x);
+            money.entry(x.currency).or_default().blocked = x.value;
+        }
+        for x in value.blocked_guarantee {
+            let x = types::Money::from(x);
+            money.entry(x.currency).or_default().blocked_guarantee = x.value;
+        }
+        Self { money }
+    }
+}